@@ -0,0 +1,111 @@
+use crate::ErrorVec;
+use std::alloc::{Allocator, Global};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// An [ErrorVec] whose storage lives in the allocator `A`, such as an arena or bump allocator.
+///
+/// Requires the nightly-only `allocator_api` feature:
+///
+/// ```
+/// #![feature(allocator_api)]
+/// use std::alloc::System;
+/// use errorvec::ErrorVecIn;
+///
+/// let mut errs = ErrorVecIn::new_in(System);
+/// errs.take_error("x".parse::<u32>());
+/// errs.push("1.5".parse::<u32>().unwrap_err());
+///
+/// assert_eq!(2, errs.len());
+/// assert!(errs.into_result().is_err());
+/// ```
+#[derive(Debug)]
+pub struct ErrorVecIn<E, A>(Vec<E, A>)
+where
+    A: Allocator;
+
+impl<E, A> ErrorVecIn<E, A>
+where
+    A: Allocator,
+{
+    /// An empty accumulator allocating from `alloc`.
+    pub fn new_in(alloc: A) -> Self {
+        ErrorVecIn(Vec::new_in(alloc))
+    }
+
+    /// As [ErrorVec::into_result].
+    pub fn into_result(self) -> Result<(), Self> {
+        self.into_result_with(())
+    }
+
+    /// As [ErrorVec::into_result_with].
+    pub fn into_result_with<T>(self, value: T) -> Result<T, Self> {
+        if self.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// As [ErrorVec::take_error].
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Convert into the underlying vector.
+    pub fn into_inner(self) -> Vec<E, A> {
+        self.0
+    }
+}
+
+impl<E, A> From<Vec<E, A>> for ErrorVecIn<E, A>
+where
+    A: Allocator,
+{
+    fn from(v: Vec<E, A>) -> Self {
+        ErrorVecIn(v)
+    }
+}
+
+impl<E> From<ErrorVecIn<E, Global>> for ErrorVec<E> {
+    fn from(ev: ErrorVecIn<E, Global>) -> Self {
+        ErrorVec::from(ev.0)
+    }
+}
+
+impl<E, A> Deref for ErrorVecIn<E, A>
+where
+    A: Allocator,
+{
+    type Target = Vec<E, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E, A> DerefMut for ErrorVecIn<E, A>
+where
+    A: Allocator,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<E, A> fmt::Display for ErrorVecIn<E, A>
+where
+    E: fmt::Display,
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter().collect::<ErrorVec<&E>>().fmt(f)
+    }
+}
+
+impl<E, A> std::error::Error for ErrorVecIn<E, A>
+where
+    E: fmt::Display + fmt::Debug,
+    A: Allocator + fmt::Debug,
+{
+}
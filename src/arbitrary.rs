@@ -0,0 +1,32 @@
+use crate::ErrorVec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generates any number of errors, including none, for fuzzing error-handling code:
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use errorvec::ErrorVec;
+///
+/// let mut u = Unstructured::new(&[1, 7, 0, 9, 0]);
+/// let ev = ErrorVec::<u8>::arbitrary(&mut u).unwrap();
+/// assert!(ev.iter().all(|e| [7, 9].contains(e)));
+///
+/// let empty = ErrorVec::<u8>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+/// assert!(empty.is_empty());
+/// ```
+impl<'a, E> Arbitrary<'a> for ErrorVec<E>
+where
+    E: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary(u).map(ErrorVec::from)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary_take_rest(u).map(ErrorVec::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Vec::<E>::size_hint(depth)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{ErrorVec, ProblemDetails, ReportEntry};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// Respond with `422 Unprocessable Entity` and a [ProblemDetails] body listing every error:
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use axum::response::IntoResponse;
+/// use errorvec::ErrorVec;
+///
+/// fn validate(name: &str, age: i32) -> Result<(), ErrorVec<&'static str>> {
+///     let mut errs = ErrorVec::default();
+///     if name.is_empty() {
+///         errs.push("name is required");
+///     }
+///     if age < 0 {
+///         errs.push("age must be positive");
+///     }
+///     errs.into_result()
+/// }
+///
+/// let resp = validate("", -1).unwrap_err().into_response();
+/// assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, resp.status());
+/// assert_eq!("application/problem+json", resp.headers()["content-type"]);
+/// ```
+///
+/// For a different status, respond with a customized [ErrorVec::to_problem_details] instead.
+impl<E> IntoResponse for ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    fn into_response(self) -> Response {
+        self.to_problem_details()
+            .with_title("Unprocessable Entity")
+            .with_status(StatusCode::UNPROCESSABLE_ENTITY.as_u16())
+            .into_response()
+    }
+}
+
+/// Respond with the [ProblemDetails] `status` and its JSON body.
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST);
+        match serde_json::to_vec(&self) {
+            Ok(body) => {
+                (status, [(CONTENT_TYPE, ProblemDetails::CONTENT_TYPE)], body).into_response()
+            }
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
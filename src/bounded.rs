@@ -0,0 +1,240 @@
+#[cfg(feature = "std")]
+use crate::errorvec::write_report_with_trailer;
+#[cfg(feature = "std")]
+use crate::strings::{FmtLocalized, Localized};
+#[cfg(feature = "std")]
+use crate::{English, ErrorVec, MultiError, ReportStrings};
+use core::fmt;
+
+/// An error accumulator with inline storage for at most `N` errors, for targets which cannot
+/// allocate, and the only type available without the `std` feature.
+///
+/// Gathering never allocates. With `std`, rendering the `Display` report allocates as for
+/// `ErrorVec`; without it, `Display` writes the report directly, leaving trailing whitespace
+/// of each error in place.
+///
+/// Errors beyond the first `N` are not stored, but counted as overflow. When any overflowed,
+/// `Display` ends with a line saying how many:
+///
+/// ```
+/// use errorvec::BoundedErrorVec;
+///
+/// fn check(reading: i32) -> Result<i32, &'static str> {
+///     if reading < 0 { Err("negative reading") } else { Ok(reading) }
+/// }
+///
+/// let mut errs = BoundedErrorVec::<_, 2>::new();
+/// let readings: Vec<_> = [3, -1, -2, 5, -7]
+///     .into_iter()
+///     .filter_map(|r| errs.take_error(check(r)))
+///     .collect();
+///
+/// assert_eq!(vec![3, 5], readings);
+/// assert!(errs.is_saturated());
+/// assert_eq!(1, errs.overflowed());
+///
+/// let expected_display = r#"
+/// [error 1 of 2] negative reading
+///
+/// [error 2 of 2] negative reading
+///
+/// [1 more error not recorded]
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.into_result().unwrap_err().to_string());
+///
+/// let mut none_stored = BoundedErrorVec::<_, 0>::new();
+/// none_stored.push("negative reading");
+/// assert_eq!("[1 more error not recorded]\n", none_stored.to_string());
+/// ```
+#[derive(Debug)]
+pub struct BoundedErrorVec<E, const N: usize> {
+    slots: [Option<E>; N],
+    len: usize,
+    overflowed: usize,
+}
+
+impl<E, const N: usize> BoundedErrorVec<E, N> {
+    /// The number of errors which can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// An empty accumulator.
+    pub fn new() -> Self {
+        BoundedErrorVec {
+            slots: core::array::from_fn(|_| None),
+            len: 0,
+            overflowed: 0,
+        }
+    }
+
+    /// Record `e`, or count it as overflow if full.
+    pub fn push(&mut self, e: E) {
+        if self.len < N {
+            self.slots[self.len] = Some(e);
+            self.len += 1;
+        } else {
+            self.overflowed += 1;
+        }
+    }
+
+    /// Record the error of `r`, if any, returning the `Ok` value if present.
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+
+    /// The number of errors stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True when no errors have been recorded, including overflow.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0 && self.overflowed == 0
+    }
+
+    /// The number of errors not stored because the accumulator was full.
+    pub fn overflowed(&self) -> usize {
+        self.overflowed
+    }
+
+    /// True if any errors overflowed.
+    pub fn is_saturated(&self) -> bool {
+        self.overflowed > 0
+    }
+
+    /// The stored errors, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        self.slots[..self.len].iter().flatten()
+    }
+
+    /// `Ok(())` if no errors were recorded, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), Self> {
+        self.into_result_with(())
+    }
+
+    /// `Ok(value)` if no errors were recorded, otherwise `Err(self)`.
+    pub fn into_result_with<T>(self, value: T) -> Result<T, Self> {
+        if self.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, const N: usize> BoundedErrorVec<E, N> {
+    /// Convert the stored errors into an [ErrorVec], discarding the overflow count.
+    pub fn into_errorvec(self) -> ErrorVec<E> {
+        self.slots.into_iter().flatten().collect()
+    }
+}
+
+impl<E, const N: usize> Default for BoundedErrorVec<E, N> {
+    fn default() -> Self {
+        BoundedErrorVec::new()
+    }
+}
+
+impl<E, const N: usize> Extend<E> for BoundedErrorVec<E, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+    }
+}
+
+/// Only the stored errors are counted and iterated. Merging pushes the stored errors of `other`
+/// and adds its overflow count.
+#[cfg(feature = "std")]
+impl<E, const N: usize> MultiError for BoundedErrorVec<E, N> {
+    type Error = E;
+
+    fn error_count(&self) -> usize {
+        self.len
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &E> {
+        self.iter()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.overflowed += other.overflowed;
+        self.extend(other.slots.into_iter().flatten());
+    }
+
+    fn into_errors(self) -> ErrorVec<E> {
+        self.into_errorvec()
+    }
+}
+
+impl<E, const N: usize> fmt::Display for BoundedErrorVec<E, N>
+where
+    E: fmt::Display,
+{
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let total = self.len;
+        for (i, e) in self.iter().enumerate() {
+            let sep = if i + 1 < total { "\n" } else { "" };
+            writeln!(f, "[error {} of {}] {}{}", i + 1, total, e, sep)?;
+        }
+        match (total, self.overflowed) {
+            (0, 0) => writeln!(f, "[no errors]"),
+            (_, 0) => Ok(()),
+            (t, n) => {
+                if t > 0 {
+                    writeln!(f)?;
+                }
+                let s = if n == 1 { "" } else { "s" };
+                writeln!(f, "[{} more error{} not recorded]", n, s)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, const N: usize> BoundedErrorVec<E, N>
+where
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including
+    /// [ReportStrings::not_recorded] for any overflow.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, const N: usize> FmtLocalized for BoundedErrorVec<E, N>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        let errors: Vec<&E> = self.iter().collect();
+        let trailer = (self.overflowed > 0).then(|| strings.not_recorded(self.overflowed));
+        write_report_with_trailer(f, &errors, strings, trailer)
+    }
+}
+
+impl<E, const N: usize> core::error::Error for BoundedErrorVec<E, N> where
+    E: fmt::Display + fmt::Debug
+{
+}
@@ -0,0 +1,120 @@
+use crate::errorvec::write_report_with_trailer;
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, ReportStrings};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A signal which cancellable gathering checks before consuming each item, such as
+/// [ResultIterator::into_errorvec_result_cancellable](crate::ResultIterator::into_errorvec_result_cancellable).
+pub trait CancelSignal {
+    /// True once gathering should stop.
+    fn is_cancelled(&self) -> bool;
+}
+
+impl CancelSignal for AtomicBool {
+    fn is_cancelled(&self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+impl<C> CancelSignal for Arc<C>
+where
+    C: CancelSignal + ?Sized,
+{
+    fn is_cancelled(&self) -> bool {
+        (**self).is_cancelled()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl CancelSignal for tokio_util::sync::CancellationToken {
+    fn is_cancelled(&self) -> bool {
+        tokio_util::sync::CancellationToken::is_cancelled(self)
+    }
+}
+
+/// The result of cancellable gathering which failed or was cancelled: everything gathered so far,
+/// and whether the input was cut short.
+///
+/// When cancelled, `Display` ends with a line saying so, which replaces the empty report if no
+/// errors were gathered:
+///
+/// ```
+/// use std::sync::atomic::AtomicBool;
+/// use errorvec::ResultIterator;
+///
+/// let cancel = AtomicBool::new(true);
+/// let outcome = [Ok::<u32, String>(1)]
+///     .into_iter()
+///     .into_errorvec_result_cancellable(&cancel)
+///     .unwrap_err();
+///
+/// assert_eq!("[cancelled; remaining input skipped]\n", outcome.to_string());
+/// ```
+#[derive(Debug)]
+pub struct PartialOutcome<T, E> {
+    /// The value built from the items consumed before stopping.
+    pub value: T,
+    /// The errors gathered before stopping.
+    pub errors: ErrorVec<E>,
+    /// True if gathering stopped before the input was exhausted.
+    pub cancelled: bool,
+}
+
+impl<T, E> PartialOutcome<T, E> {
+    /// `Ok(value)` if the input was exhausted without errors, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<T, Self> {
+        if self.cancelled || !self.errors.is_empty() {
+            Err(self)
+        } else {
+            Ok(self.value)
+        }
+    }
+}
+
+impl<T, E> fmt::Display for PartialOutcome<T, E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<T, E> PartialOutcome<T, E>
+where
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including
+    /// [ReportStrings::cancelled] if cancelled.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<T, E> FmtLocalized for PartialOutcome<T, E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        let trailer = self.cancelled.then(|| strings.cancelled());
+        write_report_with_trailer(f, &self.errors, strings, trailer)
+    }
+}
+
+impl<T, E> std::error::Error for PartialOutcome<T, E>
+where
+    T: fmt::Debug,
+    E: fmt::Display + fmt::Debug,
+{
+}
@@ -0,0 +1,243 @@
+use crate::errorvec::write_report_with_trailer;
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, MultiError, ReportStrings};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Which errors a [CappedErrorVec] retains once full.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Retention {
+    /// Keep the oldest errors, dropping new ones.
+    First,
+    /// Keep the newest errors, dropping the oldest.
+    Last,
+}
+
+/// An error accumulator with a fixed capacity, for long-lived processes which must not grow
+/// without bound.
+///
+/// Once full, errors are dropped according to its [Retention], and counted. When any were
+/// dropped, `Display` ends with a line saying how many:
+///
+/// ```
+/// use errorvec::CappedErrorVec;
+///
+/// let mut errs = CappedErrorVec::keep_last(2);
+/// for i in 1..=5 {
+///     errs.push(format!("request {i} timed out"));
+/// }
+///
+/// assert_eq!(2, errs.len());
+/// assert_eq!(3, errs.dropped());
+///
+/// let expected_display = r#"
+/// [error 1 of 2] request 4 timed out
+///
+/// [error 2 of 2] request 5 timed out
+///
+/// [3 earlier errors dropped]
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+///
+/// let mut none_kept = CappedErrorVec::keep_first(0);
+/// none_kept.push("request 1 timed out");
+/// assert_eq!("[1 later error dropped]\n", none_kept.to_string());
+/// ```
+#[derive(Debug)]
+pub struct CappedErrorVec<E> {
+    errors: VecDeque<E>,
+    capacity: usize,
+    retention: Retention,
+    dropped: usize,
+}
+
+impl<E> CappedErrorVec<E> {
+    /// Retain up to `capacity` errors according to `retention`.
+    pub fn new(capacity: usize, retention: Retention) -> Self {
+        CappedErrorVec {
+            errors: VecDeque::new(),
+            capacity,
+            retention,
+            dropped: 0,
+        }
+    }
+
+    /// Retain the first `capacity` errors.
+    pub fn keep_first(capacity: usize) -> Self {
+        CappedErrorVec::new(capacity, Retention::First)
+    }
+
+    /// Retain the last `capacity` errors.
+    pub fn keep_last(capacity: usize) -> Self {
+        CappedErrorVec::new(capacity, Retention::Last)
+    }
+
+    /// Record `e`, dropping an error if full.
+    pub fn push(&mut self, e: E) {
+        if self.errors.len() < self.capacity {
+            self.errors.push_back(e);
+            return;
+        }
+        self.dropped += 1;
+        if self.retention == Retention::Last && self.capacity > 0 {
+            self.errors.pop_front();
+            self.errors.push_back(e);
+        }
+    }
+
+    /// As [ErrorVec::take_error].
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+
+    /// The number of errors retained.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// True when no errors have been recorded, including dropped ones.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.dropped == 0
+    }
+
+    /// The number of errors dropped because the accumulator was full.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The retained errors, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter()
+    }
+
+    /// Convert the retained errors into an [ErrorVec], discarding the dropped count.
+    pub fn into_errorvec(self) -> ErrorVec<E> {
+        self.errors.into_iter().collect()
+    }
+
+    /// `Ok(())` if no errors were recorded, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<E> Extend<E> for CappedErrorVec<E> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+    }
+}
+
+/// Only the retained errors are counted and iterated. Merging pushes the retained errors of
+/// `other` and adds its dropped count; the capacity and [Retention] of `self` always win, and
+/// those of `other` are ignored:
+///
+/// ```
+/// use errorvec::{CappedErrorVec, MultiError};
+///
+/// let mut errs = CappedErrorVec::keep_first(2);
+/// errs.push("a");
+///
+/// let mut other = CappedErrorVec::keep_last(5);
+/// other.extend(["b", "c", "d"]);
+/// errs.merge(other);
+///
+/// assert_eq!(vec![&"a", &"b"], errs.errors().collect::<Vec<_>>());
+/// assert_eq!(2, errs.dropped());
+/// ```
+impl<E> MultiError for CappedErrorVec<E> {
+    type Error = E;
+
+    fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.dropped += other.dropped;
+        self.extend(other.errors);
+    }
+
+    fn into_errors(self) -> ErrorVec<E> {
+        self.into_errorvec()
+    }
+}
+
+impl<E> fmt::Display for CappedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<E> CappedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including
+    /// [ReportStrings::dropped] for any dropped errors:
+    ///
+    /// ```
+    /// use errorvec::{CappedErrorVec, ReportStrings, Retention};
+    ///
+    /// struct French;
+    ///
+    /// impl ReportStrings for French {
+    ///     fn entry_header(&self, index: usize, total: usize) -> String {
+    ///         format!("[erreur {} sur {}]", index + 1, total)
+    ///     }
+    ///
+    ///     fn dropped(&self, n: usize, _: Retention) -> String {
+    ///         format!("[{} erreur(s) antérieure(s) ignorée(s)]", n)
+    ///     }
+    /// }
+    ///
+    /// let mut errs = CappedErrorVec::keep_last(1);
+    /// errs.push("délai dépassé");
+    /// errs.push("connexion refusée");
+    ///
+    /// assert_eq!(
+    ///     "[erreur 1 sur 1] connexion refusée\n\n[1 erreur(s) antérieure(s) ignorée(s)]\n",
+    ///     errs.display_localized(&French).to_string(),
+    /// );
+    /// ```
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<E> FmtLocalized for CappedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        let errors: Vec<&E> = self.errors.iter().collect();
+        let trailer = (self.dropped > 0).then(|| strings.dropped(self.dropped, self.retention));
+        write_report_with_trailer(f, &errors, strings, trailer)
+    }
+}
+
+impl<E> std::error::Error for CappedErrorVec<E> where E: fmt::Display + fmt::Debug {}
@@ -0,0 +1,125 @@
+use crate::ErrorVec;
+use std::sync::mpsc;
+
+/// Create a channel for gathering errors from workers which cannot share an [ErrorVec]:
+///
+/// ```
+/// use std::thread;
+/// use errorvec::error_channel;
+///
+/// let (tx, collector) = error_channel();
+/// for input in ["1", "x", "3", "y"] {
+///     let tx = tx.clone();
+///     thread::spawn(move || tx.take_error(input.parse::<u32>()));
+/// }
+/// drop(tx);
+///
+/// assert_eq!(2, collector.collect().len());
+/// ```
+pub fn error_channel<E>() -> (ErrorSender<E>, ErrorCollector<E>) {
+    let (tx, rx) = mpsc::channel();
+    (ErrorSender(tx), ErrorCollector(rx))
+}
+
+/// The cloneable sending half of an [error_channel].
+#[derive(Debug)]
+pub struct ErrorSender<E>(mpsc::Sender<E>);
+
+/// The receiving half of an [error_channel].
+#[derive(Debug)]
+pub struct ErrorCollector<E>(mpsc::Receiver<E>);
+
+impl<E> ErrorSender<E> {
+    /// Send `e` to the collector. If the collector was dropped, `e` is discarded.
+    pub fn push(&self, e: E) {
+        let _ = self.0.send(e);
+    }
+
+    /// As [ErrorVec::take_error], sending any error to the collector.
+    pub fn take_error<T>(&self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+}
+
+impl<E> Clone for ErrorSender<E> {
+    fn clone(&self) -> Self {
+        ErrorSender(self.0.clone())
+    }
+}
+
+impl<E> ErrorCollector<E> {
+    /// Block until every [ErrorSender] is dropped, returning the errors in the order received.
+    pub fn collect(self) -> ErrorVec<E> {
+        self.0.into_iter().collect()
+    }
+
+    /// As [ErrorCollector::collect], then [ErrorVec::into_result].
+    pub fn into_result(self) -> Result<(), ErrorVec<E>> {
+        self.collect().into_result()
+    }
+}
+
+/// Like [error_channel], for asynchronous tasks:
+///
+/// ```
+/// use errorvec::async_error_channel;
+///
+/// let (tx, collector) = async_error_channel();
+/// for input in ["1", "x", "3"] {
+///     tx.take_error(input.parse::<u32>());
+/// }
+/// drop(tx);
+///
+/// let errs = futures::executor::block_on(collector.collect());
+/// assert_eq!(1, errs.len());
+/// ```
+#[cfg(feature = "async")]
+pub fn async_error_channel<E>() -> (AsyncErrorSender<E>, AsyncErrorCollector<E>) {
+    let (tx, rx) = futures::channel::mpsc::unbounded();
+    (AsyncErrorSender(tx), AsyncErrorCollector(rx))
+}
+
+/// The cloneable sending half of an [async_error_channel].
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncErrorSender<E>(futures::channel::mpsc::UnboundedSender<E>);
+
+/// The receiving half of an [async_error_channel].
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncErrorCollector<E>(futures::channel::mpsc::UnboundedReceiver<E>);
+
+#[cfg(feature = "async")]
+impl<E> AsyncErrorSender<E> {
+    /// Send `e` to the collector without waiting. If the collector was dropped, `e` is discarded.
+    pub fn push(&self, e: E) {
+        let _ = self.0.unbounded_send(e);
+    }
+
+    /// As [ErrorVec::take_error], sending any error to the collector.
+    pub fn take_error<T>(&self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E> Clone for AsyncErrorSender<E> {
+    fn clone(&self) -> Self {
+        AsyncErrorSender(self.0.clone())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E> AsyncErrorCollector<E> {
+    /// Wait until every [AsyncErrorSender] is dropped, returning the errors in the order received.
+    pub async fn collect(self) -> ErrorVec<E> {
+        use futures::StreamExt;
+
+        self.0.collect::<Vec<E>>().await.into()
+    }
+
+    /// As [AsyncErrorCollector::collect], then [ErrorVec::into_result].
+    pub async fn into_result(self) -> Result<(), ErrorVec<E>> {
+        self.collect().await.into_result()
+    }
+}
@@ -0,0 +1,151 @@
+use crate::ErrorVec;
+use std::cell::Cell;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// An [ErrorVec] which complains if it is dropped while holding errors that were never handled.
+///
+/// Errors count as handled once the accumulator is converted with [CheckedErrorVec::into_result]
+/// or [CheckedErrorVec::into_inner], displayed, or explicitly [dismissed](CheckedErrorVec::dismiss).
+/// Dropping it with unhandled errors panics when built with debug assertions, and otherwise prints
+/// the report to stderr:
+///
+/// ```should_panic
+/// use errorvec::CheckedErrorVec;
+///
+/// let mut errs = CheckedErrorVec::default();
+/// errs.push("forgotten");
+/// // dropped here without being handled
+/// # assert!(cfg!(debug_assertions));
+/// ```
+///
+/// ```
+/// use errorvec::CheckedErrorVec;
+///
+/// let mut errs = CheckedErrorVec::default();
+/// errs.push("handled");
+/// assert!(errs.into_result().is_err());
+/// ```
+pub struct CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    ev: Option<ErrorVec<E>>,
+    handled: Cell<bool>,
+}
+
+impl<E> CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Guard `ev`.
+    pub fn new(ev: ErrorVec<E>) -> Self {
+        CheckedErrorVec {
+            ev: Some(ev),
+            handled: Cell::new(false),
+        }
+    }
+
+    /// Take the guarded [ErrorVec], handling it.
+    pub fn into_inner(mut self) -> ErrorVec<E> {
+        self.ev.take().unwrap_or_default()
+    }
+
+    /// As [ErrorVec::into_result].
+    pub fn into_result(self) -> Result<(), ErrorVec<E>> {
+        self.into_inner().into_result()
+    }
+
+    /// As [ErrorVec::into_result_with].
+    pub fn into_result_with<T>(self, value: T) -> Result<T, ErrorVec<E>> {
+        self.into_inner().into_result_with(value)
+    }
+
+    /// Deliberately discard any errors.
+    pub fn dismiss(self) {
+        self.handled.set(true);
+    }
+
+    fn inner(&self) -> &ErrorVec<E> {
+        self.ev.as_ref().expect("present until consumed")
+    }
+}
+
+impl<E> Default for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn default() -> Self {
+        CheckedErrorVec::new(ErrorVec::default())
+    }
+}
+
+impl<E> From<ErrorVec<E>> for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn from(ev: ErrorVec<E>) -> Self {
+        CheckedErrorVec::new(ev)
+    }
+}
+
+impl<E> Deref for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    type Target = ErrorVec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner()
+    }
+}
+
+impl<E> DerefMut for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ev.as_mut().expect("present until consumed")
+    }
+}
+
+impl<E> fmt::Debug for CheckedErrorVec<E>
+where
+    E: fmt::Display + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CheckedErrorVec")
+            .field(self.inner())
+            .finish()
+    }
+}
+
+impl<E> fmt::Display for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.handled.set(true);
+        self.inner().fmt(f)
+    }
+}
+
+impl<E> Drop for CheckedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn drop(&mut self) {
+        let Some(ev) = self.ev.as_ref() else {
+            return;
+        };
+        if self.handled.get() || ev.is_empty() || std::thread::panicking() {
+            return;
+        }
+
+        if cfg!(debug_assertions) {
+            panic!("CheckedErrorVec dropped with unhandled errors:\n{}", ev);
+        } else {
+            eprintln!("CheckedErrorVec dropped with unhandled errors:\n{}", ev);
+        }
+    }
+}
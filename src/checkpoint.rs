@@ -0,0 +1,68 @@
+use crate::ErrorVec;
+
+/// A position within an [ErrorVec], produced by [ErrorVec::checkpoint], for checking whether
+/// later steps added errors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(usize);
+
+impl<E> ErrorVec<E> {
+    /// Mark the current end of the errors:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs = ErrorVec::default();
+    /// errs.push("unrelated earlier failure");
+    ///
+    /// let cp = errs.checkpoint();
+    /// let port = errs.take_error("80x".parse::<u16>().map_err(|_| "bad port"));
+    /// if errs.is_clean_since(cp) {
+    ///     println!("binding to {}", port.unwrap());
+    /// }
+    ///
+    /// assert_eq!(&["bad port"], errs.errors_since(cp));
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.len())
+    }
+
+    /// The errors added after `cp`.
+    ///
+    /// Errors removed since `cp` was taken may cause later additions to be missed.
+    pub fn errors_since(&self, cp: Checkpoint) -> &[E] {
+        self.get(cp.0..).unwrap_or_default()
+    }
+
+    /// True if no errors were added after `cp`.
+    pub fn is_clean_since(&self, cp: Checkpoint) -> bool {
+        self.errors_since(cp).is_empty()
+    }
+
+    /// Run `f`, keeping any errors it adds, and return `Ok` of its value if it added none, or
+    /// `Err` of how many it added:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs = ErrorVec::default();
+    /// let parsed = errs.scope(|errs| {
+    ///     errs.take_error("1".parse::<u32>());
+    ///     errs.take_error("x".parse::<u32>());
+    /// });
+    ///
+    /// assert_eq!(Err(1), parsed);
+    /// assert_eq!(Ok(()), errs.scope(|_| ()));
+    /// assert_eq!(1, errs.len());
+    /// ```
+    pub fn scope<T, F>(&mut self, f: F) -> Result<T, usize>
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let cp = self.checkpoint();
+        let value = f(self);
+        match self.errors_since(cp).len() {
+            0 => Ok(value),
+            added => Err(added),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::ErrorVec;
+
+/// When a [ChunksGathering] iterator gives up on a chunk, or on the whole input.
+///
+/// The default gathers every item of every chunk.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChunkPolicy {
+    max_errors: Option<usize>,
+    abort_run: bool,
+}
+
+impl ChunkPolicy {
+    /// Gather every item of every chunk.
+    pub fn gather_all() -> Self {
+        ChunkPolicy::default()
+    }
+
+    /// Once a chunk has more than `max_errors` errors, skip the rest of that chunk and continue
+    /// with the next one. Skipped items are still pulled from the underlying iterator, but their
+    /// results are discarded.
+    pub fn abort_chunk_after(max_errors: usize) -> Self {
+        ChunkPolicy {
+            max_errors: Some(max_errors),
+            abort_run: false,
+        }
+    }
+
+    /// Once a chunk has more than `max_errors` errors, yield that chunk's errors and stop.
+    pub fn abort_run_after(max_errors: usize) -> Self {
+        ChunkPolicy {
+            max_errors: Some(max_errors),
+            abort_run: true,
+        }
+    }
+
+    fn exceeded(&self, errors: usize) -> bool {
+        self.max_errors.map(|max| errors > max).unwrap_or(false)
+    }
+}
+
+/// An [Iterator] yielding a `Result<Vec<O>, ErrorVec<E>>` per fixed-size chunk of an underlying
+/// iterator over `Result<O, E>`, produced by
+/// [ResultIterator::chunks_gathering](crate::ResultIterator::chunks_gathering).
+#[derive(Debug)]
+pub struct ChunksGathering<I> {
+    inner: I,
+    size: usize,
+    policy: ChunkPolicy,
+    done: bool,
+}
+
+impl<I> ChunksGathering<I> {
+    pub(crate) fn new(inner: I, size: usize, policy: ChunkPolicy) -> Self {
+        assert!(size > 0, "chunk size must be non-zero");
+        ChunksGathering {
+            inner,
+            size,
+            policy,
+            done: false,
+        }
+    }
+}
+
+impl<I, O, E> Iterator for ChunksGathering<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<Vec<O>, ErrorVec<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut oks = Vec::with_capacity(self.size.min(self.inner.size_hint().0));
+        let mut ev = ErrorVec::default();
+        let mut taken = 0;
+        while taken < self.size {
+            let Some(result) = self.inner.next() else {
+                self.done = true;
+                break;
+            };
+            taken += 1;
+            if let Some(v) = ev.take_error(result) {
+                oks.push(v);
+            }
+            if self.policy.exceeded(ev.len()) {
+                if self.policy.abort_run {
+                    self.done = true;
+                } else {
+                    self.inner.by_ref().take(self.size - taken).for_each(drop);
+                }
+                break;
+            }
+        }
+
+        if taken == 0 {
+            None
+        } else {
+            Some(ev.into_result_with(oks))
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// An error paired with a stable diagnostic code, such as `E0423`.
+///
+/// `Display` prefixes the error with its bracketed code:
+///
+/// ```
+/// use errorvec::{Coded, ErrorVec};
+///
+/// let mut ev = ErrorVec::default();
+/// ev.push(Coded::new("E0423", "expected value, found struct `Foo`"));
+/// ev.push(Coded::new("E0308", "mismatched types"));
+///
+/// let expected_display = r#"
+/// [error 1 of 2] [E0423] expected value, found struct `Foo`
+///
+/// [error 2 of 2] [E0308] mismatched types
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &ev.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Coded<E> {
+    /// The diagnostic code.
+    pub code: String,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> Coded<E> {
+    /// Pair `error` with `code`.
+    pub fn new<S>(code: S, error: E) -> Self
+    where
+        S: Into<String>,
+    {
+        Coded {
+            code: code.into(),
+            error,
+        }
+    }
+
+    /// Discard the code.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Coded<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.error)
+    }
+}
+
+impl<E> std::error::Error for Coded<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Coded<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, metadata, span, severity, children);
+
+    fn code(&self) -> Option<String> {
+        Some(self.code.clone())
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// The errors whose [ReportEntry::code] is `code`.
+    pub fn with_code<'a>(&'a self, code: &'a str) -> impl Iterator<Item = &'a E> + 'a {
+        self.iter()
+            .filter(move |e| e.code().as_deref() == Some(code))
+    }
+
+    /// Map each [ReportEntry::code] present to the 1-based report positions of its errors:
+    ///
+    /// ```
+    /// use errorvec::{Coded, ErrorVec};
+    ///
+    /// let ev: ErrorVec<_> = [
+    ///     Coded::new("E0308", "mismatched types"),
+    ///     Coded::new("E0425", "cannot find value `x`"),
+    ///     Coded::new("E0308", "mismatched types"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let index = ev.code_index();
+    /// assert_eq!(vec![1, 3], index["E0308"]);
+    /// assert_eq!(vec![2], index["E0425"]);
+    /// assert_eq!(2, ev.with_code("E0308").count());
+    /// ```
+    pub fn code_index(&self) -> BTreeMap<String, Vec<usize>> {
+        let mut index: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, e) in self.iter().enumerate() {
+            if let Some(code) = e.code() {
+                index.entry(code).or_default().push(i + 1);
+            }
+        }
+        index
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{ErrorVec, LineCol, ReportEntry, Severity, Span};
+use codespan_reporting::diagnostic::{self, Diagnostic, Label};
+use codespan_reporting::files::{Error, Files};
+use codespan_reporting::term::{self, Config, WriteStyle};
+use std::ops::Range;
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Convert every entry into a codespan [Diagnostic], to render with source excerpts through
+    /// [ErrorVec::emit_codespan] or [ErrorVec::codespan_to_string].
+    ///
+    /// An entry's [ReportEntry::span] becomes its primary label when `file_id` finds the span's
+    /// source among `files`, using the span's byte range if known and its line/column range
+    /// otherwise. The message comes from [ReportEntry::message], the severity from
+    /// [ReportEntry::severity], the code from [ReportEntry::code], and each cause in
+    /// [ReportEntry::source_chain] becomes a note:
+    ///
+    /// ```
+    /// use codespan_reporting::files::SimpleFiles;
+    /// use codespan_reporting::term::Config;
+    /// use errorvec::{Coded, ErrorVec, Span, Spanned};
+    ///
+    /// let mut files = SimpleFiles::new();
+    /// let main = files.add("main.rs", "let x = 1 +;\n");
+    ///
+    /// let ev: ErrorVec<_> = [Spanned::new(
+    ///     Span::at("main.rs", 1, 12),
+    ///     Coded::new("E0001", "expected expression"),
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let diags = ev.to_codespan_diagnostics(&files, |name| (name == "main.rs").then_some(main));
+    /// assert_eq!("expected expression", diags[0].message);
+    /// assert_eq!(11..11, diags[0].labels[0].range);
+    ///
+    /// let rendered = diags.codespan_to_string(&Config::default(), &files).unwrap();
+    /// assert!(rendered.contains("error[E0001]"));
+    /// assert!(rendered.contains("main.rs:1:12"));
+    /// ```
+    pub fn to_codespan_diagnostics<'files, F, L>(
+        &self,
+        files: &'files F,
+        mut file_id: L,
+    ) -> ErrorVec<Diagnostic<F::FileId>>
+    where
+        F: Files<'files> + ?Sized,
+        L: FnMut(&str) -> Option<F::FileId>,
+    {
+        self.iter()
+            .map(|e| {
+                let severity = match e.severity() {
+                    Severity::Hint => diagnostic::Severity::Help,
+                    Severity::Note => diagnostic::Severity::Note,
+                    Severity::Warning => diagnostic::Severity::Warning,
+                    Severity::Error | Severity::Fatal => diagnostic::Severity::Error,
+                };
+                let label = e.span().and_then(|span| {
+                    let id = file_id(&span.source)?;
+                    Some(Label::primary(id, byte_range(files, id, span)))
+                });
+                let mut diag = Diagnostic::new(severity)
+                    .with_message(e.message().trim_end())
+                    .with_labels(label.into_iter().collect())
+                    .with_notes(e.source_chain());
+                if let Some(code) = e.code() {
+                    diag = diag.with_code(code);
+                }
+                diag
+            })
+            .collect()
+    }
+}
+
+/// The byte range of `span` within file `id`.
+fn byte_range<'files, F>(files: &'files F, id: F::FileId, span: &Span) -> Range<usize>
+where
+    F: Files<'files> + ?Sized,
+{
+    if let Some(bytes) = &span.bytes {
+        return bytes.clone();
+    }
+    let start = byte_offset(files, id, span.start);
+    start..byte_offset(files, id, span.end).max(start)
+}
+
+/// The byte offset of `lc` within file `id`, clamped to the end of its line, or of the file.
+fn byte_offset<'files, F>(files: &'files F, id: F::FileId, lc: LineCol) -> usize
+where
+    F: Files<'files> + ?Sized,
+{
+    let Ok(source) = files.source(id) else {
+        return 0;
+    };
+    let text = source.as_ref();
+    let Ok(line) = files.line_range(id, lc.line.saturating_sub(1)) else {
+        return text.len();
+    };
+    let line_text = text.get(line.clone()).unwrap_or("");
+    line_text
+        .char_indices()
+        .nth(lc.column.saturating_sub(1))
+        .map_or(line.end, |(i, _)| line.start + i)
+}
+
+impl<FileId> ErrorVec<Diagnostic<FileId>> {
+    /// Render every diagnostic through [codespan_reporting::term], with source excerpts and
+    /// annotations, to a styled writer such as a `termcolor::StandardStream`.
+    pub fn emit_codespan<'files, F, W>(
+        &self,
+        writer: &mut W,
+        config: &Config,
+        files: &'files F,
+    ) -> Result<(), Error>
+    where
+        F: Files<'files, FileId = FileId> + ?Sized,
+        W: WriteStyle + ?Sized,
+    {
+        for diagnostic in self.iter() {
+            term::emit_to_write_style(writer, config, files, diagnostic)?;
+        }
+        Ok(())
+    }
+
+    /// Render every diagnostic through [codespan_reporting::term] into an uncolored `String`:
+    ///
+    /// ```
+    /// use codespan_reporting::diagnostic::{Diagnostic, Label};
+    /// use codespan_reporting::files::SimpleFiles;
+    /// use codespan_reporting::term::Config;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut files = SimpleFiles::new();
+    /// let file_id = files.add("main.rs", "let x = 1 +;\n");
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// ev.push(
+    ///     Diagnostic::error()
+    ///         .with_message("expected expression")
+    ///         .with_labels(vec![Label::primary(file_id, 11..12)]),
+    /// );
+    ///
+    /// let rendered = ev.codespan_to_string(&Config::default(), &files).unwrap();
+    /// assert!(rendered.contains("error: expected expression"));
+    /// assert!(rendered.contains("main.rs:1:12"));
+    /// ```
+    pub fn codespan_to_string<'files, F>(
+        &self,
+        config: &Config,
+        files: &'files F,
+    ) -> Result<String, Error>
+    where
+        F: Files<'files, FileId = FileId> + ?Sized,
+    {
+        let mut s = String::new();
+        for diagnostic in self.iter() {
+            term::emit_to_string(&mut s, config, files, diagnostic)?;
+        }
+        Ok(s)
+    }
+}
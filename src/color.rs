@@ -0,0 +1,113 @@
+use crate::{English, ErrorVec, ReportEntry, ReportStrings, Severity};
+use std::fmt;
+use std::io::IsTerminal;
+
+/// Whether [ErrorVec::display_colored] emits ANSI color codes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when stderr is a terminal and the `NO_COLOR` environment variable is unset or empty.
+    #[default]
+    Auto,
+    /// Always color.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolve [ColorChoice::Auto] against the current environment.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                let no_color = std::env::var_os("NO_COLOR")
+                    .map(|v| !v.is_empty())
+                    .unwrap_or(false);
+                !no_color && std::io::stderr().is_terminal()
+            }
+        }
+    }
+}
+
+/// The [ErrorVec] report with ANSI colors, produced by [ErrorVec::display_colored].
+#[derive(Debug)]
+pub struct ColoredReport<'a, E, S: ?Sized = English> {
+    ev: &'a ErrorVec<E>,
+    enabled: bool,
+    strings: &'a S,
+}
+
+impl<'a, E, S: ?Sized> ColoredReport<'a, E, S> {
+    /// Use the framing text from `strings`; see [ReportStrings].
+    pub fn localized<T>(self, strings: &'a T) -> ColoredReport<'a, E, T>
+    where
+        T: ReportStrings + ?Sized,
+    {
+        ColoredReport {
+            ev: self.ev,
+            enabled: self.enabled,
+            strings,
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Display the report with each `[error K of N]` header colored by the entry's
+    /// [ReportEntry::severity], when `choice` allows color:
+    ///
+    /// ```
+    /// use errorvec::{ColorChoice, ErrorVec};
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops"].into_iter().collect();
+    /// assert_eq!(
+    ///     "\u{1b}[1;31m[error 1 of 1]\u{1b}[0m whoops\n",
+    ///     ev.display_colored(ColorChoice::Always).to_string(),
+    /// );
+    /// assert_eq!(ev.to_string(), ev.display_colored(ColorChoice::Never).to_string());
+    /// ```
+    pub fn display_colored(&self, choice: ColorChoice) -> ColoredReport<'_, E> {
+        ColoredReport {
+            ev: self,
+            enabled: choice.enabled(),
+            strings: &English,
+        }
+    }
+}
+
+impl<E, S> fmt::Display for ColoredReport<'_, E, S>
+where
+    E: ReportEntry,
+    S: ReportStrings + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.enabled || self.ev.is_empty() {
+            return self.ev.display_localized(self.strings).fmt(f);
+        }
+
+        let total = self.ev.len();
+        for (i, e) in self.ev.iter().enumerate() {
+            let color = match e.severity() {
+                Severity::Hint | Severity::Note => "1;36",
+                Severity::Warning => "1;33",
+                Severity::Error => "1;31",
+                Severity::Fatal => "1;35",
+            };
+            let edisp = e.to_string();
+            writeln!(
+                f,
+                "\x1b[{}m{}\x1b[0m {}",
+                color,
+                self.strings.entry_header(i, total),
+                edisp.trim_end()
+            )?;
+            if i + 1 < total {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
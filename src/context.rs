@@ -0,0 +1,87 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+
+/// An error paired with context describing what was being attempted when it occurred.
+///
+/// `Display` prefixes the error with its context. See [ErrorVec::take_error_with].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Context<C, E> {
+    /// What was being attempted.
+    pub context: C,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<C, E> Context<C, E> {
+    /// Pair `error` with `context`.
+    pub fn new(context: C, error: E) -> Self {
+        Context { context, error }
+    }
+
+    /// Discard the context.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<C, E> fmt::Display for Context<C, E>
+where
+    C: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.error)
+    }
+}
+
+impl<C, E> std::error::Error for Context<C, E>
+where
+    C: fmt::Display + fmt::Debug,
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<C, E> ReportEntry for Context<C, E>
+where
+    C: fmt::Display,
+    E: ReportEntry,
+{
+    forward_report_entry!(error; key, source_chain, metadata, span, severity, children, code);
+
+    fn message(&self) -> String {
+        format!("{}: {}", self.context, self.error.message())
+    }
+
+    fn group(&self) -> Option<(String, &dyn ReportEntry)> {
+        Some((self.context.to_string(), &self.error))
+    }
+}
+
+impl<C, E> ErrorVec<Context<C, E>> {
+    /// Like [ErrorVec::take_error], attaching the context produced by `ctx` to the error if
+    /// present. `ctx` is only called on error:
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs = ErrorVec::default();
+    /// let path = Path::new("/this/path/does/not/exist");
+    /// let content = errs.take_error_with(std::fs::read_to_string(path), || {
+    ///     format!("reading {}", path.display())
+    /// });
+    ///
+    /// assert!(content.is_none());
+    /// assert!(errs[0].to_string().starts_with("reading /this/path/does/not/exist: "));
+    /// ```
+    pub fn take_error_with<T, F>(&mut self, r: Result<T, E>, ctx: F) -> Option<T>
+    where
+        F: FnOnce() -> C,
+    {
+        self.take_error(r.map_err(|error| Context::new(ctx(), error)))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{ErrorVec, ReportEntry};
+use std::io;
+
+/// The header row of [ErrorVec::write_csv] and [ErrorVec::write_tsv].
+pub const CSV_COLUMNS: [&str; 5] = ["index", "key", "severity", "code", "message"];
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Write a header row and one row per entry to `w` as CSV, with the columns of
+    /// [CSV_COLUMNS]. The index is 1-based, `key` and `code` are empty when absent, and the
+    /// message is [ReportEntry::message], without the prefixes repeated in other columns.
+    ///
+    /// Fields containing the delimiter, quotes, or line breaks are quoted as in RFC 4180:
+    ///
+    /// ```
+    /// use errorvec::{Coded, ErrorVec};
+    ///
+    /// let ev: ErrorVec<_> = [
+    ///     Coded::new("E1", "bad value \"x\""),
+    ///     Coded::new("E2", "line 1\nline 2"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut out = vec![];
+    /// ev.write_csv(&mut out).unwrap();
+    ///
+    /// let expected = "index,key,severity,code,message\r\n\
+    ///     1,,error,E1,\"bad value \"\"x\"\"\"\r\n\
+    ///     2,,error,E2,\"line 1\nline 2\"\r\n";
+    /// assert_eq!(expected, String::from_utf8(out).unwrap());
+    /// ```
+    pub fn write_csv<W>(&self, w: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_delimited(w, ',')
+    }
+
+    /// As [ErrorVec::write_csv], separating fields with tabs.
+    pub fn write_tsv<W>(&self, w: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_delimited(w, '\t')
+    }
+
+    fn write_delimited<W>(&self, mut w: W, delimiter: char) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_row(&mut w, delimiter, CSV_COLUMNS.map(str::to_string))?;
+        for (i, e) in self.iter().enumerate() {
+            write_row(
+                &mut w,
+                delimiter,
+                [
+                    (i + 1).to_string(),
+                    e.key().unwrap_or_default(),
+                    e.severity().to_string(),
+                    e.code().unwrap_or_default(),
+                    e.message().trim_end().to_string(),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn write_row<W, const N: usize>(w: &mut W, delimiter: char, fields: [String; N]) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            write!(w, "{}", delimiter)?;
+        }
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            write!(w, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            w.write_all(field.as_bytes())?;
+        }
+    }
+    w.write_all(b"\r\n")
+}
@@ -0,0 +1,123 @@
+use crate::ErrorVec;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::ops::Deref;
+
+/// An [ErrorVec] which skips errors equal to one already recorded, in constant expected time per
+/// error, for large batches where [ErrorVec::push_unique] would be quadratic:
+///
+/// ```
+/// use errorvec::DedupErrorVec;
+///
+/// let mut errs = DedupErrorVec::default();
+/// for i in 0..10_000 {
+///     errs.push(format!("shard {} unreachable", i % 3));
+/// }
+///
+/// assert_eq!(3, errs.len());
+/// assert_eq!(9_997, errs.suppressed());
+/// assert_eq!("shard 0 unreachable", errs[0]);
+/// ```
+///
+/// Only shared access to the inner [ErrorVec] is provided, so that every error passes through
+/// the duplicate check.
+pub struct DedupErrorVec<E> {
+    ev: ErrorVec<E>,
+    index: HashMap<u64, Vec<usize>>,
+    hasher: RandomState,
+    suppressed: usize,
+}
+
+impl<E> Default for DedupErrorVec<E> {
+    fn default() -> Self {
+        DedupErrorVec {
+            ev: ErrorVec::default(),
+            index: HashMap::new(),
+            hasher: RandomState::new(),
+            suppressed: 0,
+        }
+    }
+}
+
+impl<E> DedupErrorVec<E>
+where
+    E: Hash + Eq,
+{
+    /// Record `e` unless an equal error was already recorded, returning whether it was recorded.
+    pub fn push(&mut self, e: E) -> bool {
+        let bucket = self.index.entry(self.hasher.hash_one(&e)).or_default();
+        if bucket.iter().any(|&i| self.ev[i] == e) {
+            self.suppressed += 1;
+            return false;
+        }
+        bucket.push(self.ev.len());
+        self.ev.push(e);
+        true
+    }
+
+    /// As [ErrorVec::take_error], skipping the error if an equal error was already recorded.
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+}
+
+impl<E> DedupErrorVec<E> {
+    /// The number of errors skipped as duplicates.
+    pub fn suppressed(&self) -> usize {
+        self.suppressed
+    }
+
+    /// Discard the duplicate index.
+    pub fn into_errorvec(self) -> ErrorVec<E> {
+        self.ev
+    }
+
+    /// Discard the duplicate index, then [ErrorVec::into_result].
+    pub fn into_result(self) -> Result<(), ErrorVec<E>> {
+        self.ev.into_result()
+    }
+}
+
+impl<E> Extend<E> for DedupErrorVec<E>
+where
+    E: Hash + Eq,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+    }
+}
+
+impl<E> Deref for DedupErrorVec<E> {
+    type Target = ErrorVec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ev
+    }
+}
+
+impl<E> fmt::Debug for DedupErrorVec<E>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupErrorVec")
+            .field("ev", &self.ev)
+            .field("suppressed", &self.suppressed)
+            .finish()
+    }
+}
+
+impl<E> fmt::Display for DedupErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.ev.fmt(f)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::BoundedErrorVec;
+#[cfg(feature = "std")]
+use crate::ErrorVec;
+use defmt::{Format, Formatter};
+
+/// Emits the `Display` report framing, `[error K of N]` per entry, with each error rendered by
+/// its own [Format] impl rather than by `core::fmt`. Requires the `std` feature, unlike the impl
+/// for [BoundedErrorVec]:
+///
+/// ```
+/// use errorvec::{BoundedErrorVec, ErrorVec};
+///
+/// fn assert_format<T: defmt::Format>() {}
+///
+/// assert_format::<ErrorVec<u32>>();
+/// assert_format::<BoundedErrorVec<u32, 4>>();
+/// ```
+#[cfg(feature = "std")]
+impl<E> Format for ErrorVec<E>
+where
+    E: Format,
+{
+    fn format(&self, f: Formatter) {
+        if self.is_empty() {
+            defmt::write!(f, "[no errors]\n");
+        } else {
+            format_entries(f, self.iter(), self.len());
+        }
+    }
+}
+
+/// As for `ErrorVec`, ending with a line counting any overflow. Available without `std`, for
+/// logging from embedded targets.
+impl<E, const N: usize> Format for BoundedErrorVec<E, N>
+where
+    E: Format,
+{
+    fn format(&self, f: Formatter) {
+        let total = self.len();
+        let overflowed = self.overflowed();
+        if total > 0 {
+            format_entries(f, self.iter(), total);
+            if overflowed > 0 {
+                defmt::write!(f, "\n");
+            }
+        } else if overflowed == 0 {
+            defmt::write!(f, "[no errors]\n");
+        }
+        match overflowed {
+            0 => {}
+            1 => defmt::write!(f, "[1 more error not recorded]\n"),
+            n => defmt::write!(f, "[{=usize} more errors not recorded]\n", n),
+        }
+    }
+}
+
+fn format_entries<'a, E, I>(f: Formatter, errors: I, total: usize)
+where
+    E: Format + 'a,
+    I: Iterator<Item = &'a E>,
+{
+    for (i, e) in errors.enumerate() {
+        let sep = if i + 1 < total { "\n" } else { "" };
+        defmt::write!(
+            f,
+            "[error {=usize} of {=usize}] {}\n{=str}",
+            i + 1,
+            total,
+            e,
+            sep
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ErrorVec;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The differences between two [ErrorVec]s, produced by [ErrorVec::diff] or
+/// [ErrorVec::diff_by_key].
+///
+/// Errors are compared by key, so entries are counted rather than ordered. Each map is ordered
+/// by key, keeping `Display` stable for snapshot tests.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorVecDiff {
+    /// Keys present only after, with their count.
+    pub added: BTreeMap<String, usize>,
+    /// Keys present only before, with their count.
+    pub removed: BTreeMap<String, usize>,
+    /// Keys present on both sides a different number of times, with the counts before and after.
+    pub changed: BTreeMap<String, (usize, usize)>,
+}
+
+impl ErrorVecDiff {
+    /// True when both sides had the same keys the same number of times.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ErrorVecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "[no differences]");
+        }
+        for (key, &count) in &self.removed {
+            write_line(f, '-', key, count)?;
+        }
+        for (key, &count) in &self.added {
+            write_line(f, '+', key, count)?;
+        }
+        for (key, (before, after)) in &self.changed {
+            writeln!(f, "~ {} ({} -> {} occurrences)", key, before, after)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_line(f: &mut fmt::Formatter, sign: char, key: &str, count: usize) -> fmt::Result {
+    if count == 1 {
+        writeln!(f, "{} {}", sign, key)
+    } else {
+        writeln!(f, "{} {} ({} occurrences)", sign, key, count)
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Compare `self`, as before, with `other`, as after, keyed by each error's `Display` text:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let before: ErrorVec<&str> = ["unused import", "missing `;`", "unused import"]
+    ///     .into_iter()
+    ///     .collect();
+    /// let after: ErrorVec<&str> = ["unused import", "type mismatch"].into_iter().collect();
+    ///
+    /// let expected_display = r#"
+    /// - missing `;`
+    /// + type mismatch
+    /// ~ unused import (2 -> 1 occurrences)
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, before.diff(&after).to_string());
+    /// assert!(after.diff(&after).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Self) -> ErrorVecDiff {
+        self.diff_by_key(other, |e| e.to_string().trim_end().to_string())
+    }
+}
+
+impl<E> ErrorVec<E> {
+    /// Compare `self`, as before, with `other`, as after, keyed by `key`, such as an error code
+    /// or a message with volatile details like paths removed.
+    pub fn diff_by_key<F>(&self, other: &Self, key: F) -> ErrorVecDiff
+    where
+        F: Fn(&E) -> String,
+    {
+        let count = |ev: &Self| {
+            let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+            for e in ev.iter() {
+                *counts.entry(key(e)).or_default() += 1;
+            }
+            counts
+        };
+        let mut removed = count(self);
+        let mut added = count(other);
+
+        let mut changed = BTreeMap::new();
+        removed.retain(|k, before| match added.remove(k) {
+            None => true,
+            Some(after) => {
+                if after != *before {
+                    changed.insert(k.clone(), (*before, after));
+                }
+                false
+            }
+        });
+
+        ErrorVecDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+}
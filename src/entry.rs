@@ -0,0 +1,221 @@
+use crate::{ErrorVec, Severity, Span};
+use std::error::Error;
+use std::fmt;
+
+/// Structured details of a single error entry, used by the machine-readable report formats.
+///
+/// Every method has a default, so error types only need to opt in:
+///
+/// ```
+/// use errorvec::ReportEntry;
+///
+/// #[derive(Debug, derive_more::Display)]
+/// #[display(fmt = "bad record")]
+/// struct BadRecord;
+///
+/// impl ReportEntry for BadRecord {}
+///
+/// assert!(BadRecord.source_chain().is_empty());
+/// ```
+///
+/// Types implementing [std::error::Error] can report their cause chain with [error_source_chain]:
+///
+/// ```
+/// use errorvec::{error_source_chain, ReportEntry};
+///
+/// #[derive(Debug, derive_more::Display)]
+/// #[display(fmt = "could not load config")]
+/// struct ConfigError(std::io::Error);
+///
+/// impl std::error::Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// impl ReportEntry for ConfigError {
+///     fn source_chain(&self) -> Vec<String> {
+///         error_source_chain(self)
+///     }
+/// }
+///
+/// let e = ConfigError(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"));
+/// assert_eq!(vec!["no such file".to_string()], e.source_chain());
+/// ```
+pub trait ReportEntry: fmt::Display {
+    /// The text of this entry, without the prefixes wrappers such as [Spanned](crate::Spanned)
+    /// and [Coded](crate::Coded) add for details the structured formats report separately:
+    ///
+    /// ```
+    /// use errorvec::{Coded, LineCol, ReportEntry, Span, Spanned};
+    ///
+    /// let span = Span::new("main.ml", LineCol::new(1, 9), LineCol::new(1, 12));
+    /// let e = Coded::new("E0425", Spanned::new(span, "unknown identifier `foo`"));
+    ///
+    /// assert_eq!("[E0425] main.ml:1:9: unknown identifier `foo`", e.to_string());
+    /// assert_eq!("unknown identifier `foo`", e.message());
+    /// ```
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    /// A name identifying what this entry is about, such as an input path or field name.
+    fn key(&self) -> Option<String> {
+        None
+    }
+
+    /// The display text of each underlying cause of this entry, outermost first.
+    fn source_chain(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Key/value context attached to this entry.
+    fn metadata(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    /// The source location of this entry.
+    fn span(&self) -> Option<&Span> {
+        None
+    }
+
+    /// How serious this entry is.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Entries grouped beneath this one, such as the errors of a nested [ErrorVec].
+    fn children(&self) -> Vec<&dyn ReportEntry> {
+        vec![]
+    }
+
+    /// A stable diagnostic code identifying the kind of this entry, such as `E0423`.
+    fn code(&self) -> Option<String> {
+        None
+    }
+
+    /// A label under which [ErrorVec::display_tree] nests this entry with others sharing it,
+    /// such as a [Scoped](crate::Scoped) scope, paired with the entry to show beneath the label.
+    fn group(&self) -> Option<(String, &dyn ReportEntry)> {
+        None
+    }
+}
+
+/// Collect the display text of each error in `e.source()` chain, excluding `e` itself.
+pub fn error_source_chain(e: &dyn Error) -> Vec<String> {
+    let mut chain = vec![];
+    let mut next = e.source();
+    while let Some(src) = next {
+        chain.push(src.to_string());
+        next = src.source();
+    }
+    chain
+}
+
+/// A nested [ErrorVec] is an entry whose children are its errors, and whose severity is the
+/// highest among them.
+impl<E> ReportEntry for ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    fn severity(&self) -> Severity {
+        self.iter()
+            .map(ReportEntry::severity)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn children(&self) -> Vec<&dyn ReportEntry> {
+        self.iter().map(|e| e as &dyn ReportEntry).collect()
+    }
+}
+
+impl ReportEntry for &str {}
+impl ReportEntry for String {}
+
+macro_rules! impl_report_entry_for_errors {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl ReportEntry for $t {
+                fn source_chain(&self) -> Vec<String> {
+                    error_source_chain(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_report_entry_for_errors!(
+    std::io::Error,
+    std::fmt::Error,
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::str::Utf8Error,
+    std::string::FromUtf8Error
+);
+
+impl ReportEntry for Box<dyn Error> {
+    fn source_chain(&self) -> Vec<String> {
+        error_source_chain(self.as_ref())
+    }
+}
+
+impl ReportEntry for Box<dyn Error + Send + Sync> {
+    fn source_chain(&self) -> Vec<String> {
+        error_source_chain(self.as_ref())
+    }
+}
+
+/// Within an `impl ReportEntry` for a wrapper, define each listed method by forwarding it to the
+/// wrapped entry, named by a field (`error; key, code`) or a method (`example(); key, code`).
+/// The wrapper writes out only the methods it changes.
+macro_rules! forward_report_entry {
+    ($field:ident; $($method:ident),+ $(,)?) => {
+        $( $crate::entry::forward_report_entry!(@$method [$field]); )+
+    };
+    ($accessor:ident(); $($method:ident),+ $(,)?) => {
+        $( $crate::entry::forward_report_entry!(@$method [$accessor()]); )+
+    };
+    (@message [$($inner:tt)+]) => {
+        fn message(&self) -> String {
+            self.$($inner)+.message()
+        }
+    };
+    (@key [$($inner:tt)+]) => {
+        fn key(&self) -> Option<String> {
+            self.$($inner)+.key()
+        }
+    };
+    (@source_chain [$($inner:tt)+]) => {
+        fn source_chain(&self) -> Vec<String> {
+            self.$($inner)+.source_chain()
+        }
+    };
+    (@metadata [$($inner:tt)+]) => {
+        fn metadata(&self) -> Vec<(String, String)> {
+            self.$($inner)+.metadata()
+        }
+    };
+    (@span [$($inner:tt)+]) => {
+        fn span(&self) -> Option<&$crate::Span> {
+            self.$($inner)+.span()
+        }
+    };
+    (@severity [$($inner:tt)+]) => {
+        fn severity(&self) -> $crate::Severity {
+            self.$($inner)+.severity()
+        }
+    };
+    (@children [$($inner:tt)+]) => {
+        fn children(&self) -> Vec<&dyn $crate::ReportEntry> {
+            self.$($inner)+.children()
+        }
+    };
+    (@code [$($inner:tt)+]) => {
+        fn code(&self) -> Option<String> {
+            self.$($inner)+.code()
+        }
+    };
+}
+
+pub(crate) use forward_report_entry;
@@ -0,0 +1,38 @@
+use crate::ErrorVec;
+use error_stack::Report;
+use std::error::Error;
+
+impl<C> ErrorVec<Report<C>> {
+    /// Merge every gathered [error_stack::Report] into one multi-context `Report<[C]>`, preserving
+    /// each report's frames and attachments, or `None` if there are no errors:
+    ///
+    /// ```
+    /// use error_stack::Report;
+    /// use errorvec::ResultIterator;
+    ///
+    /// fn parse(s: &str) -> Result<u32, Report<std::num::ParseIntError>> {
+    ///     s.parse()
+    ///         .map_err(|e| Report::new(e).attach(format!("while parsing {s:?}")))
+    /// }
+    ///
+    /// let ev = ["1", "x", "-2"].into_iter().map(parse).into_errorvec_result().unwrap_err();
+    /// let report = ev.merge_reports().unwrap();
+    /// assert_eq!(2, report.current_contexts().count());
+    /// ```
+    pub fn merge_reports(self) -> Option<Report<[C]>> {
+        self.into_iter().collect()
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Wrap each error in a new [error_stack::Report] and merge them into one multi-context
+    /// `Report<[E]>`, or `None` if there are no errors.
+    ///
+    /// To instead keep the numbered report as a single context, use `Report::new(errorvec)`.
+    pub fn into_report(self) -> Option<Report<[E]>> {
+        self.into_iter().map(Report::new).collect()
+    }
+}
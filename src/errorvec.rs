@@ -1,3 +1,5 @@
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ReportStrings};
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 
@@ -59,6 +61,17 @@ use std::ops::{Deref, DerefMut};
 /// assert!(ev.into_result().is_ok());
 /// ```
 ///
+/// Displaying an empty [ErrorVec] renders an explicit marker rather than blank output, so a
+/// forgotten `into_result` is visible in logs. Use [ErrorVec::display_or] to choose the text:
+///
+/// ```
+/// use errorvec::ErrorVec;
+///
+/// let ev: ErrorVec<&str> = ErrorVec::default();
+/// assert_eq!("[no errors]\n", ev.to_string());
+/// assert_eq!("all checks passed", ev.display_or("all checks passed").to_string());
+/// ```
+///
 /// # Example - Gathering errors with `take_error` and `into_result_with`
 ///
 /// For scenarios where [ResultIterator](crate::ResultIterator) isn't
@@ -89,7 +102,7 @@ use std::ops::{Deref, DerefMut};
 ///     errs.into_result_with(contents)
 /// }
 /// ```
-#[derive(Debug, derive_more::From, derive_more::Into)]
+#[derive(derive_more::From, derive_more::Into)]
 pub struct ErrorVec<E>(Vec<E>);
 
 impl<E> ErrorVec<E> {
@@ -117,10 +130,418 @@ impl<E> ErrorVec<E> {
             }
         }
     }
+
+    /// Like [ErrorVec::take_error], awaiting `fut` for the result:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// async fn fetch(id: u32) -> Result<String, String> {
+    ///     if id % 2 == 0 { Ok(format!("item {id}")) } else { Err(format!("item {id} not found")) }
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut errs = ErrorVec::default();
+    ///     let a = errs.take_error_async(fetch(2)).await;
+    ///     let b = errs.take_error_async(fetch(3)).await;
+    ///
+    ///     assert_eq!(Some("item 2".to_string()), a);
+    ///     assert_eq!(None, b);
+    ///     assert_eq!(1, errs.len());
+    /// });
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn take_error_async<T, F>(&mut self, fut: F) -> Option<T>
+    where
+        F: std::future::Future<Output = Result<T, E>>,
+    {
+        self.take_error(fut.await)
+    }
+
+    /// Like [ErrorVec::take_error], converting the error with [Into] as `?` would:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs: ErrorVec<Box<dyn std::error::Error>> = ErrorVec::default();
+    /// let n = errs.take_error_map("42".parse::<u32>());
+    /// let x = errs.take_error_map("4.2.".parse::<f64>());
+    ///
+    /// assert_eq!(Some(42), n);
+    /// assert_eq!(None, x);
+    /// assert_eq!(1, errs.len());
+    /// ```
+    pub fn take_error_map<T, E2>(&mut self, r: Result<T, E2>) -> Option<T>
+    where
+        E2: Into<E>,
+    {
+        self.take_error(r.map_err(Into::into))
+    }
+
+    /// Run a fallible block, collecting its error if it fails, otherwise returning its `Ok` value.
+    ///
+    /// This allows `?` within the block while still gathering every block's failure:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs: ErrorVec<std::num::ParseIntError> = ErrorVec::default();
+    /// let sums: Vec<u32> = ["1 2", "3 x", "5 6"]
+    ///     .into_iter()
+    ///     .filter_map(|pair| {
+    ///         errs.catch(|| {
+    ///             let (a, b) = pair.split_once(' ').unwrap();
+    ///             Ok(a.parse::<u32>()? + b.parse::<u32>()?)
+    ///         })
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(vec![3, 11], sums);
+    /// assert_eq!(1, errs.len());
+    /// ```
+    pub fn catch<T, F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        self.take_error(f())
+    }
+
+    /// Combine all errors into one by repeatedly applying `f`, or `None` if there are none:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<String> = ["bad port", "missing host"].map(String::from).into_iter().collect();
+    /// assert_eq!(Some("bad port; missing host".to_string()), ev.reduce(|a, b| a + "; " + &b));
+    /// ```
+    pub fn reduce<F>(self, f: F) -> Option<E>
+    where
+        F: FnMut(E, E) -> E,
+    {
+        self.0.into_iter().reduce(f)
+    }
+
+    /// Convert into a single combined error `F` with [From], or `None` if there are no errors.
+    pub fn reduce_into<F>(self) -> Option<F>
+    where
+        F: From<Self>,
+    {
+        (!self.is_empty()).then(|| F::from(self))
+    }
 }
 
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Panic with `msg` followed by the full report if there are any errors:
+    ///
+    /// ```should_panic
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops"].into_iter().collect();
+    /// ev.expect_empty("phase 1"); // panics with "phase 1: 1 error:\n[error 1 of 1] whoops\n"
+    /// ```
+    #[track_caller]
+    pub fn expect_empty(&self, msg: &str) {
+        if !self.is_empty() {
+            panic!("{}: {}:\n{}", msg, count_errors(self.len()), self);
+        }
+    }
+
+    /// Display `default` if there are no errors, otherwise display the usual report.
+    pub fn display_or<'a, D>(&'a self, default: D) -> impl fmt::Display + 'a
+    where
+        D: fmt::Display + 'a,
+    {
+        DisplayOr { ev: self, default }
+    }
+
+    /// Display the report beneath a one-line overview naming the error count and, optionally, the
+    /// `subject` being worked on. An empty report gets no overview:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["missing `name`", "bad port"].into_iter().collect();
+    ///
+    /// let expected_display = r#"
+    /// error: 2 errors occurred while loading configuration:
+    /// [error 1 of 2] missing `name`
+    ///
+    /// [error 2 of 2] bad port
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(
+    ///     expected_display,
+    ///     ev.display_with_summary(Some("loading configuration")).to_string(),
+    /// );
+    ///
+    /// let one: ErrorVec<&str> = ["bad port"].into_iter().collect();
+    /// assert_eq!(
+    ///     "error: 1 error occurred:\n[error 1 of 1] bad port\n",
+    ///     one.display_with_summary(None).to_string(),
+    /// );
+    /// ```
+    pub fn display_with_summary<'a>(&'a self, subject: Option<&'a str>) -> impl fmt::Display + 'a {
+        self.display_with_summary_localized(subject, &English)
+    }
+
+    /// As [ErrorVec::display_with_summary], with the framing text from `strings`.
+    pub fn display_with_summary_localized<'a, S>(
+        &'a self,
+        subject: Option<&'a str>,
+        strings: &'a S,
+    ) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        DisplayWithSummary {
+            ev: self,
+            subject,
+            strings,
+        }
+    }
+
+    /// Display the report with the framing text from `strings`; see [ReportStrings].
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+
+    /// Display the report with the errors stably sorted by `key`, leaving `self` unchanged.
+    ///
+    /// To sort in place, use [slice::sort_by_key] through [DerefMut], which is also stable:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut ev: ErrorVec<&str> = ["b: second", "a: first", "b: third"].into_iter().collect();
+    /// let sorted = ev.display_sorted_by_key(|e| e.split(':').next()).to_string();
+    ///
+    /// ev.sort_by_key(|e| e.split(':').next());
+    /// assert_eq!(ev.to_string(), sorted);
+    /// assert_eq!(&["a: first", "b: second", "b: third"], ev.as_slice());
+    /// ```
+    pub fn display_sorted_by_key<'a, K, F>(&'a self, key: F) -> impl fmt::Display + 'a
+    where
+        K: Ord,
+        F: Fn(&E) -> K + 'a,
+    {
+        DisplaySorted { ev: self, key }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: PartialEq,
+{
+    /// Push `e` unless an equal error is already present, returning whether it was pushed.
+    ///
+    /// Each call scans the existing errors, so deduplicating `n` errors costs O(n²) comparisons.
+    /// This suits the modest error counts typical of reports; for large batches of hashable
+    /// errors, use [DedupErrorVec](crate::DedupErrorVec). To count suppressed duplicates, tally
+    /// the `false` results:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// let mut suppressed = 0;
+    /// for e in ["timeout", "refused", "timeout", "timeout"] {
+    ///     if !ev.push_unique(e) {
+    ///         suppressed += 1;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(&["timeout", "refused"], ev.as_slice());
+    /// assert_eq!(2, suppressed);
+    /// ```
+    pub fn push_unique(&mut self, e: E) -> bool {
+        if self.contains(&e) {
+            false
+        } else {
+            self.push(e);
+            true
+        }
+    }
+
+    /// Like [ErrorVec::take_error], skipping the error if an equal error is already present.
+    pub fn take_error_unique<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        match r {
+            Ok(x) => Some(x),
+            Err(e) => {
+                self.push_unique(e);
+                None
+            }
+        }
+    }
+}
+
+impl<E> ErrorVec<E> {
+    /// Push `e` unless an error with an equal key is already present, returning whether it was
+    /// pushed. As with [ErrorVec::push_unique], each call scans the existing errors:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// ev.push_unique_by_key(io::Error::from(io::ErrorKind::NotFound), io::Error::kind);
+    /// ev.push_unique_by_key(io::Error::from(io::ErrorKind::NotFound), io::Error::kind);
+    ///
+    /// assert_eq!(1, ev.len());
+    /// ```
+    pub fn push_unique_by_key<K, F>(&mut self, e: E, key: F) -> bool
+    where
+        K: PartialEq,
+        F: Fn(&E) -> K,
+    {
+        let k = key(&e);
+        if self.iter().any(|existing| key(existing) == k) {
+            false
+        } else {
+            self.push(e);
+            true
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: std::error::Error + 'static,
+{
+    /// Iterate over every error along with each error's full `source()` chain, in order.
+    ///
+    /// This makes it straightforward to search for a particular root cause:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ErrorVec;
+    ///
+    /// #[derive(Debug, derive_more::Display)]
+    /// #[display(fmt = "could not load {}", _0)]
+    /// struct LoadError(&'static str, io::Error);
+    ///
+    /// impl std::error::Error for LoadError {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.1)
+    ///     }
+    /// }
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// ev.push(LoadError("a.txt", io::ErrorKind::NotFound.into()));
+    /// ev.push(LoadError("b.txt", io::ErrorKind::PermissionDenied.into()));
+    ///
+    /// assert_eq!(4, ev.sources().count());
+    /// assert!(ev.sources().any(|e| {
+    ///     e.downcast_ref::<io::Error>()
+    ///         .map(|e| e.kind() == io::ErrorKind::PermissionDenied)
+    ///         .unwrap_or(false)
+    /// }));
+    /// ```
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        self.iter().flat_map(|e| {
+            std::iter::successors(Some(e as &(dyn std::error::Error + 'static)), |e| {
+                e.source()
+            })
+        })
+    }
+}
+
+/// `Debug` lists each entry's `Debug` form on its own line, numbered as in the `Display` report,
+/// so that panics from `unwrap` and `expect` stay legible:
+///
+/// ```
+/// use errorvec::ErrorVec;
+///
+/// let ev: ErrorVec<_> = ["x", "300"]
+///     .into_iter()
+///     .filter_map(|s| s.parse::<u8>().err())
+///     .collect();
+/// let expected_debug = r#"
+/// ErrorVec [
+///     [error 1 of 2] ParseIntError { kind: InvalidDigit },
+///     [error 2 of 2] ParseIntError { kind: PosOverflow },
+/// ]"#.trim_start();
+///
+/// assert_eq!(expected_debug, format!("{:?}", ev));
+/// assert_eq!("ErrorVec []", format!("{:?}", ErrorVec::<()>::default()));
+/// ```
+///
+/// With `{:#?}`, each entry is pretty-printed beneath its header's indentation.
+impl<E> fmt::Debug for ErrorVec<E>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("ErrorVec []");
+        }
+        writeln!(f, "ErrorVec [")?;
+        let total = self.len();
+        for (i, e) in self.iter().enumerate() {
+            let edbg = if f.alternate() {
+                format!("{:#?}", e)
+            } else {
+                format!("{:?}", e)
+            };
+            writeln!(
+                f,
+                "    {} {},",
+                entry_header(i, total),
+                edbg.replace('\n', "\n    ")
+            )?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// An [ErrorVec] is an error whenever its entries are displayable, and is `Send`, `Sync`, and
+/// `'static` whenever they are, so it works as a `#[source]` or `#[from]` field of a
+/// `thiserror` enum and converts into `Box<dyn Error + Send + Sync>`:
+///
+/// ```
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+/// use errorvec::{ErrorVec, ResultIterator};
+///
+/// #[derive(Debug, thiserror::Error)]
+/// enum ConfigError {
+///     #[error("invalid port list")]
+///     Ports(#[from] ErrorVec<ParseIntError>),
+/// }
+///
+/// fn parse_ports(s: &str) -> Result<Vec<u16>, ConfigError> {
+///     Ok(s.split(',').map(str::parse).into_errorvec_result()?)
+/// }
+///
+/// let err = parse_ports("80,http,x").unwrap_err();
+/// let ports = err.source().unwrap().downcast_ref::<ErrorVec<ParseIntError>>().unwrap();
+/// assert_eq!(2, ports.len());
+///
+/// let boxed: Box<dyn Error + Send + Sync> = err.into();
+/// assert_eq!("invalid port list", boxed.to_string());
+/// ```
+///
+/// The entries are not reported by [Error::source](std::error::Error::source), which has room for
+/// only one; see [ErrorVec::sources]. To combine errors of differing types, convert them with
+/// [MultiError::into_boxed](crate::MultiError::into_boxed).
 impl<E> std::error::Error for ErrorVec<E> where E: fmt::Display + fmt::Debug {}
 
+/// An empty [ErrorVec] does not allocate, so gathering which encounters no errors allocates no
+/// error storage:
+///
+/// ```
+/// use errorvec::ErrorVec;
+///
+/// assert_eq!(0, ErrorVec::<String>::default().capacity());
+/// ```
 impl<E> Default for ErrorVec<E> {
     fn default() -> Self {
         ErrorVec(vec![])
@@ -150,6 +571,24 @@ impl<E> FromIterator<E> for ErrorVec<E> {
     }
 }
 
+impl<E> ErrorVec<ErrorVec<E>> {
+    /// Concatenate nested errors into a single flat [ErrorVec], in order:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let parse: ErrorVec<&str> = ["unexpected `}`"].into_iter().collect();
+    /// let check: ErrorVec<&str> = ["unknown type `Foo`", "unused import"].into_iter().collect();
+    ///
+    /// let stages: ErrorVec<ErrorVec<&str>> = [parse, check].into_iter().collect();
+    /// let flat = stages.flatten();
+    /// assert_eq!(&["unexpected `}`", "unknown type `Foo`", "unused import"], flat.as_slice());
+    /// ```
+    pub fn flatten(self) -> ErrorVec<E> {
+        self.into_iter().flatten().collect()
+    }
+}
+
 impl<E> IntoIterator for ErrorVec<E> {
     type Item = E;
     type IntoIter = <Vec<E> as IntoIterator>::IntoIter;
@@ -159,19 +598,208 @@ impl<E> IntoIterator for ErrorVec<E> {
     }
 }
 
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Render each entry as it appears in the `Display` report, one string per error, for UIs
+    /// which list errors individually:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops", "ouch!\n"].into_iter().collect();
+    /// let entries: Vec<String> = ev.display_entries().collect();
+    ///
+    /// assert_eq!(vec!["[error 1 of 2] whoops", "[error 2 of 2] ouch!"], entries);
+    /// ```
+    ///
+    /// Unlike the report, an empty [ErrorVec] yields no entries, and entries carry no trailing
+    /// newline or blank separator line.
+    pub fn display_entries(&self) -> impl Iterator<Item = String> + '_ {
+        display_entries(self, &English)
+    }
+
+    /// As [ErrorVec::display_entries], with the entry headers from `strings`.
+    pub fn display_entries_localized<'a, S>(
+        &'a self,
+        strings: &'a S,
+    ) -> impl Iterator<Item = String> + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        display_entries(self, strings)
+    }
+
+    /// The pieces of the `Display` report, in order, each ending in a newline.
+    pub(crate) fn report_chunks(&self) -> impl Iterator<Item = String> + '_ {
+        report_chunks(self)
+    }
+}
+
+/// The entries of `errors` as rendered by [ErrorVec::display_entries].
+fn display_entries<'a, E, S>(errors: &'a [E], strings: &'a S) -> impl Iterator<Item = String> + 'a
+where
+    E: fmt::Display,
+    S: ReportStrings + ?Sized,
+{
+    let total = errors.len();
+    errors.iter().enumerate().map(move |(i, e)| {
+        let edisp = e.to_string();
+        format!("{} {}", strings.entry_header(i, total), edisp.trim_end())
+    })
+}
+
+/// The pieces of the `Display` report of `errors`, in order, each ending in a newline.
+pub(crate) fn report_chunks<E>(errors: &[E]) -> impl Iterator<Item = String> + '_
+where
+    E: fmt::Display,
+{
+    report_chunks_localized(errors, &English)
+}
+
+/// As [report_chunks], with the framing text from `strings`.
+pub(crate) fn report_chunks_localized<'a, E, S>(
+    errors: &'a [E],
+    strings: &'a S,
+) -> impl Iterator<Item = String> + 'a
+where
+    E: fmt::Display,
+    S: ReportStrings + ?Sized,
+{
+    let total = errors.len();
+    let empty = (total == 0).then(|| format!("{}\n", strings.no_errors()));
+    empty.into_iter().chain(
+        display_entries(errors, strings)
+            .enumerate()
+            .map(move |(i, entry)| {
+                let sep = if i + 1 < total { "\n" } else { "" };
+                format!("{}\n{}", entry, sep)
+            }),
+    )
+}
+
+/// Write the report of `errors` with the framing text from `strings`, followed by a blank line
+/// and the `trailer` line if any. A trailer replaces the report of no errors.
+pub(crate) fn write_report_with_trailer<E, S>(
+    f: &mut fmt::Formatter,
+    errors: &[E],
+    strings: &S,
+    trailer: Option<String>,
+) -> fmt::Result
+where
+    E: fmt::Display,
+    S: ReportStrings + ?Sized,
+{
+    if !errors.is_empty() || trailer.is_none() {
+        for chunk in report_chunks_localized(errors, strings) {
+            f.write_str(&chunk)?;
+        }
+    }
+    if let Some(trailer) = trailer {
+        if !errors.is_empty() {
+            writeln!(f)?;
+        }
+        writeln!(f, "{}", trailer)?;
+    }
+    Ok(())
+}
+
+/// `"1 error"` or `"N errors"`.
+pub(crate) fn count_errors(n: usize) -> String {
+    count_of(n, "error")
+}
+
+/// `n` followed by `noun`, pluralized with an `s` unless `n` is 1, such as `"2 attempts"`.
+pub(crate) fn count_of(n: usize, noun: &str) -> String {
+    format!("{} {}{}", n, noun, if n == 1 { "" } else { "s" })
+}
+
+/// The `[error K of N]` header of the entry at 0-based `index`.
+pub(crate) fn entry_header(index: usize, total: usize) -> String {
+    format!("[error {} of {}]", index + 1, total)
+}
+
 impl<E> fmt::Display for ErrorVec<E>
 where
     E: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let total = self.0.len();
-        for (i, e) in self.0.iter().enumerate() {
-            let edisp = e.to_string();
-            writeln!(f, "[error {} of {}] {}", i + 1, total, edisp.trim_end())?;
-            if i + 1 < total {
-                writeln!(f)?;
-            }
+        for chunk in self.report_chunks() {
+            f.write_str(&chunk)?;
         }
         Ok(())
     }
 }
+
+struct DisplayOr<'a, E, D> {
+    ev: &'a ErrorVec<E>,
+    default: D,
+}
+
+impl<E, D> fmt::Display for DisplayOr<'_, E, D>
+where
+    E: fmt::Display,
+    D: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.ev.is_empty() {
+            self.default.fmt(f)
+        } else {
+            self.ev.fmt(f)
+        }
+    }
+}
+
+struct DisplayWithSummary<'a, E, S: ?Sized> {
+    ev: &'a ErrorVec<E>,
+    subject: Option<&'a str>,
+    strings: &'a S,
+}
+
+impl<E, S> fmt::Display for DisplayWithSummary<'_, E, S>
+where
+    E: fmt::Display,
+    S: ReportStrings + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.ev.is_empty() {
+            let header = self.strings.summary_header(self.ev.len(), self.subject);
+            writeln!(f, "{}", header)?;
+        }
+        self.ev.display_localized(self.strings).fmt(f)
+    }
+}
+
+impl<E> FmtLocalized for ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        for chunk in report_chunks_localized(self, strings) {
+            f.write_str(&chunk)?;
+        }
+        Ok(())
+    }
+}
+
+struct DisplaySorted<'a, E, F> {
+    ev: &'a ErrorVec<E>,
+    key: F,
+}
+
+impl<E, K, F> fmt::Display for DisplaySorted<'_, E, F>
+where
+    E: fmt::Display,
+    K: Ord,
+    F: Fn(&E) -> K,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut sorted: ErrorVec<&E> = self.ev.iter().collect();
+        sorted.sort_by_key(|e| (self.key)(e));
+        sorted.fmt(f)
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+
+/// A declaration of an error which should occur, for test suites which check the diagnostics
+/// produced for an input. See [ErrorVec::match_expected].
+///
+/// Each criterion given must hold for an error to match; an [ExpectedError] with none matches
+/// any error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedError {
+    /// A substring of the error's `Display` text.
+    pub substring: Option<String>,
+    /// The [ReportEntry::code] of the error.
+    pub code: Option<String>,
+    /// A source and 1-based line which the error's [ReportEntry::span] must cover.
+    pub line: Option<(String, usize)>,
+}
+
+impl ExpectedError {
+    /// Expect an error whose text contains `substring`.
+    pub fn containing<S>(substring: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ExpectedError::default().with_substring(substring)
+    }
+
+    /// Require the text to contain `substring`.
+    pub fn with_substring<S>(self, substring: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ExpectedError {
+            substring: Some(substring.into()),
+            ..self
+        }
+    }
+
+    /// Require the code to be `code`.
+    pub fn with_code<S>(self, code: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ExpectedError {
+            code: Some(code.into()),
+            ..self
+        }
+    }
+
+    /// Require the span to be within `source` and to cover `line`.
+    pub fn with_line<S>(self, source: S, line: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        ExpectedError {
+            line: Some((source.into(), line)),
+            ..self
+        }
+    }
+
+    /// True if `e` meets every criterion.
+    pub fn matches<E>(&self, e: &E) -> bool
+    where
+        E: ReportEntry,
+    {
+        self.substring
+            .as_ref()
+            .is_none_or(|s| e.to_string().contains(s.as_str()))
+            && self
+                .code
+                .as_ref()
+                .is_none_or(|c| e.code().as_ref() == Some(c))
+            && self.line.as_ref().is_none_or(|(source, line)| {
+                e.span().is_some_and(|span| {
+                    &span.source == source && (span.start.line..=span.end.line).contains(line)
+                })
+            })
+    }
+}
+
+impl fmt::Display for ExpectedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an error")?;
+        if let Some(s) = &self.substring {
+            write!(f, " containing {:?}", s)?;
+        }
+        if let Some(c) = &self.code {
+            write!(f, " with code {}", c)?;
+        }
+        if let Some((source, line)) = &self.line {
+            write!(f, " at {}:{}", source, line)?;
+        }
+        Ok(())
+    }
+}
+
+/// The differences between the expected and actual errors, produced by
+/// [ErrorVec::match_expected].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectationMismatch {
+    /// The expectations no error matched, in declaration order.
+    pub missing: Vec<ExpectedError>,
+    /// The errors no expectation matched, rendered as in the `Display` report.
+    pub unexpected: Vec<String>,
+}
+
+impl fmt::Display for ExpectationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.missing.is_empty() {
+            writeln!(f, "expected but not found ({}):", self.missing.len())?;
+            for expected in &self.missing {
+                writeln!(f, "  - {}", expected)?;
+            }
+        }
+        if !self.unexpected.is_empty() {
+            writeln!(f, "found but not expected ({}):", self.unexpected.len())?;
+            for entry in &self.unexpected {
+                writeln!(f, "  - {}", entry)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ExpectationMismatch {}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Pair each of `expected` with a distinct matching error, returning the unmatched
+    /// expectations and errors on either side, if any.
+    ///
+    /// Expectations are matched in order, each against the first matching error not already
+    /// paired:
+    ///
+    /// ```
+    /// use errorvec::{Coded, ErrorVec, ExpectedError};
+    ///
+    /// let ev: ErrorVec<Coded<&str>> = [
+    ///     Coded::new("E0425", "cannot find value `x`"),
+    ///     Coded::new("W0001", "unused import `io`"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mismatch = ev
+    ///     .match_expected(&[
+    ///         ExpectedError::containing("cannot find value").with_code("E0425"),
+    ///         ExpectedError::containing("mismatched types"),
+    ///     ])
+    ///     .unwrap_err();
+    ///
+    /// let expected_display = r#"
+    /// expected but not found (1):
+    ///   - an error containing "mismatched types"
+    /// found but not expected (1):
+    ///   - [error 2 of 2] [W0001] unused import `io`
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, mismatch.to_string());
+    /// ```
+    pub fn match_expected(&self, expected: &[ExpectedError]) -> Result<(), ExpectationMismatch> {
+        let mut matched = vec![false; self.len()];
+        let mut missing = vec![];
+        for exp in expected {
+            let found = self
+                .iter()
+                .enumerate()
+                .position(|(i, e)| !matched[i] && exp.matches(e));
+            match found {
+                Some(i) => matched[i] = true,
+                None => missing.push(exp.clone()),
+            }
+        }
+
+        let unexpected: Vec<String> = self
+            .display_entries()
+            .zip(matched)
+            .filter_map(|(entry, m)| (!m).then_some(entry))
+            .collect();
+
+        if missing.is_empty() && unexpected.is_empty() {
+            Ok(())
+        } else {
+            Err(ExpectationMismatch {
+                missing,
+                unexpected,
+            })
+        }
+    }
+
+    /// Panic with the [ExpectationMismatch] if the errors do not match `expected`, as
+    /// [ErrorVec::match_expected].
+    #[track_caller]
+    pub fn assert_expected(&self, expected: &[ExpectedError]) {
+        if let Err(mismatch) = self.match_expected(expected) {
+            panic!("errors did not match expectations:\n{}", mismatch);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display + fmt::Debug + Send + Sync + 'static,
+{
+    /// Convert into an [eyre::Report] whose message is the full numbered report.
+    ///
+    /// This is the same conversion `?` performs in functions returning [eyre::Result]. Gathering
+    /// from iterators over `Result<T, eyre::Report>` works with
+    /// [ResultIterator](crate::ResultIterator) as usual:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// fn parse_all(inputs: &[&str]) -> eyre::Result<Vec<u32>> {
+    ///     let nums = inputs
+    ///         .iter()
+    ///         .map(|s| -> eyre::Result<u32> { Ok(s.parse()?) })
+    ///         .into_errorvec_result()
+    ///         .map_err(|ev| ev.into_eyre_report())?;
+    ///     Ok(nums)
+    /// }
+    ///
+    /// let report = parse_all(&["1", "x", "-2"]).unwrap_err();
+    /// let expected_display = r#"
+    /// [error 1 of 2] invalid digit found in string
+    ///
+    /// [error 2 of 2] invalid digit found in string
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, report.to_string());
+    /// ```
+    ///
+    /// Entries are not attached as separate sections or notes, which eyre itself does not
+    /// provide; they belong to report handlers such as `color-eyre`. Instead, the [ErrorVec]
+    /// remains reachable by downcasting:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<String> = vec!["bad port".to_string()].into();
+    /// let report = ev.into_eyre_report();
+    ///
+    /// let ev = report.downcast_ref::<ErrorVec<String>>().unwrap();
+    /// assert_eq!("bad port", ev[0]);
+    /// ```
+    pub fn into_eyre_report(self) -> eyre::Report {
+        eyre::Report::new(self)
+    }
+}
+
+impl ReportEntry for eyre::Report {
+    fn source_chain(&self) -> Vec<String> {
+        self.chain().skip(1).map(|e| e.to_string()).collect()
+    }
+}
@@ -0,0 +1,124 @@
+use crate::errorvec::write_report_with_trailer;
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, MultiError, ReportEntry, ReportStrings, Severity};
+use std::fmt;
+use std::ops::Deref;
+
+/// The errors gathered by
+/// [ResultIterator::into_errorvec_result_until_fatal](crate::ResultIterator::into_errorvec_result_until_fatal),
+/// noting whether gathering stopped early at a fatal error.
+///
+/// When truncated, `Display` ends with a line saying the remaining input was skipped.
+#[derive(Debug)]
+pub struct TruncatedErrorVec<E> {
+    /// The errors gathered, ending with the fatal error if truncated.
+    pub errors: ErrorVec<E>,
+    /// True if gathering stopped at a fatal error before the input was exhausted.
+    pub truncated: bool,
+}
+
+impl<E> TruncatedErrorVec<E> {
+    /// Discard the truncation flag.
+    pub fn into_errorvec(self) -> ErrorVec<E> {
+        self.errors
+    }
+}
+
+impl<E> Deref for TruncatedErrorVec<E> {
+    type Target = ErrorVec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.errors
+    }
+}
+
+impl<E> From<TruncatedErrorVec<E>> for ErrorVec<E> {
+    fn from(t: TruncatedErrorVec<E>) -> Self {
+        t.errors
+    }
+}
+
+/// Merging keeps the result truncated if either side was.
+impl<E> MultiError for TruncatedErrorVec<E> {
+    type Error = E;
+
+    fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.errors.extend(other.errors);
+        self.truncated |= other.truncated;
+    }
+
+    fn into_errors(self) -> ErrorVec<E> {
+        self.errors
+    }
+}
+
+impl<E> fmt::Display for TruncatedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<E> TruncatedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including
+    /// [ReportStrings::truncated] if truncated.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<E> FmtLocalized for TruncatedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        let trailer = self.truncated.then(|| strings.truncated());
+        write_report_with_trailer(f, &self.errors, strings, trailer)
+    }
+}
+
+impl<E> std::error::Error for TruncatedErrorVec<E> where E: fmt::Display + fmt::Debug {}
+
+/// Like a nested [ErrorVec], but [Severity::Fatal] when truncated.
+impl<E> ReportEntry for TruncatedErrorVec<E>
+where
+    E: ReportEntry,
+{
+    fn metadata(&self) -> Vec<(String, String)> {
+        vec![("truncated".to_string(), self.truncated.to_string())]
+    }
+
+    fn severity(&self) -> Severity {
+        if self.truncated {
+            Severity::Fatal
+        } else {
+            self.errors.severity()
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn ReportEntry> {
+        self.errors.children()
+    }
+}
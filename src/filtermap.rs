@@ -0,0 +1,60 @@
+use crate::ErrorVec;
+
+/// An [Iterator] yielding the values kept by a skip, keep, or fail step while gathering every
+/// error, produced by
+/// [ResultIterator::filter_map_gathering](crate::ResultIterator::filter_map_gathering).
+#[derive(Debug)]
+pub struct FilterMapGathering<I, F, E> {
+    inner: I,
+    f: F,
+    errors: ErrorVec<E>,
+}
+
+impl<I, F, E> FilterMapGathering<I, F, E> {
+    pub(crate) fn new(inner: I, f: F) -> Self {
+        FilterMapGathering {
+            inner,
+            f,
+            errors: ErrorVec::default(),
+        }
+    }
+
+    /// The errors gathered so far.
+    pub fn errors(&self) -> &ErrorVec<E> {
+        &self.errors
+    }
+}
+
+impl<I, F, O, U, E> FilterMapGathering<I, F, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<Option<U>, E>,
+{
+    /// Consume the rest of the input, returning the remaining kept values, or every error
+    /// gathered, including those gathered before this call.
+    pub fn into_errorvec_result(mut self) -> Result<Vec<U>, ErrorVec<E>> {
+        let kept: Vec<U> = self.by_ref().collect();
+        self.errors.into_result_with(kept)
+    }
+}
+
+impl<I, F, O, U, E> Iterator for FilterMapGathering<I, F, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<Option<U>, E>,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        for result in self.inner.by_ref() {
+            if let Some(Some(v)) = self.errors.take_error(result.and_then(&mut self.f)) {
+                return Some(v);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// A report format chosen at runtime, such as from a `--error-format` flag.
+///
+/// It parses from and displays as its lowercase name:
+///
+/// ```
+/// use errorvec::ErrorFormat;
+///
+/// let format: ErrorFormat = "short".parse().unwrap();
+/// assert_eq!(ErrorFormat::Short, format);
+/// assert_eq!("short", format.to_string());
+/// assert!("xml".parse::<ErrorFormat>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorFormat {
+    /// The numbered `Display` report.
+    #[default]
+    Human,
+    /// One line per error, with line breaks within an error joined by spaces.
+    Short,
+    /// The [JsonReport](crate::JsonReport) on one line.
+    #[cfg(feature = "serde_json")]
+    Json,
+}
+
+impl ErrorFormat {
+    /// Every format, in declaration order.
+    pub const ALL: &'static [ErrorFormat] = &[
+        ErrorFormat::Human,
+        ErrorFormat::Short,
+        #[cfg(feature = "serde_json")]
+        ErrorFormat::Json,
+    ];
+
+    /// The name parsed and displayed.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorFormat::Human => "human",
+            ErrorFormat::Short => "short",
+            #[cfg(feature = "serde_json")]
+            ErrorFormat::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for ErrorFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ErrorFormat {
+    type Err = UnknownErrorFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorFormat::ALL
+            .iter()
+            .copied()
+            .find(|format| format.name() == s)
+            .ok_or_else(|| UnknownErrorFormat(s.to_string()))
+    }
+}
+
+/// The error parsing an unrecognized [ErrorFormat] name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownErrorFormat(pub String);
+
+impl fmt::Display for UnknownErrorFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = ErrorFormat::ALL.iter().map(|f| f.name()).collect();
+        write!(
+            f,
+            "unknown error format {:?}, expected one of: {}",
+            self.0,
+            names.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownErrorFormat {}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Render the report in `format`:
+    ///
+    /// ```
+    /// use errorvec::{ErrorFormat, ErrorVec};
+    ///
+    /// let ev: ErrorVec<&str> = ["missing `;`\n  --> main.rs:3", "unused import"].into_iter().collect();
+    ///
+    /// assert_eq!(ev.to_string(), ev.render(ErrorFormat::Human));
+    /// assert_eq!(
+    ///     "missing `;` --> main.rs:3\nunused import\n",
+    ///     ev.render(ErrorFormat::Short),
+    /// );
+    /// ```
+    pub fn render(&self, format: ErrorFormat) -> String {
+        let mut out = vec![];
+        self.write_rendered(format, &mut out)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(out).expect("every format renders utf-8")
+    }
+
+    /// Write the report in `format` to `w`.
+    pub fn write_rendered<W>(&self, format: ErrorFormat, mut w: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        match format {
+            ErrorFormat::Human => self.write_report(w),
+            ErrorFormat::Short => {
+                for e in self.iter() {
+                    let text = e.to_string();
+                    let lines: Vec<&str> = text
+                        .lines()
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .collect();
+                    writeln!(w, "{}", lines.join(" "))?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "serde_json")]
+            ErrorFormat::Json => {
+                serde_json::to_writer(&mut w, &self.to_json_report())?;
+                writeln!(w)
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Filesystem batch operations which attempt every path and gather every failure.
+//!
+//! ```
+//! use errorvec::fs::{read_all, remove_all};
+//!
+//! let dir = std::env::temp_dir().join("errorvec-fs-doctest");
+//! std::fs::create_dir_all(&dir).unwrap();
+//! let present = dir.join("present.txt");
+//! std::fs::write(&present, "hello").unwrap();
+//! let missing = dir.join("missing.txt");
+//!
+//! let errs = read_all([&present, &missing]).unwrap_err();
+//! assert_eq!(1, errs.len());
+//! assert_eq!(missing, errs[0].path);
+//!
+//! let contents = read_all([&present]).unwrap();
+//! assert_eq!(vec![(present.clone(), "hello".to_string())], contents);
+//!
+//! remove_all([&present]).unwrap();
+//! assert!(remove_all([&present]).is_err());
+//! ```
+
+use crate::{ErrorVec, PathError, ResultIterator};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Read every path to a string, returning each path with its contents in order, or every failure.
+pub fn read_all<I, P>(paths: I) -> Result<Vec<(PathBuf, String)>, ErrorVec<PathError<io::Error>>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let mut errs = ErrorVec::default();
+    let mut contents = vec![];
+    for path in paths {
+        let path = path.as_ref();
+        if let Some(s) = errs.take_error_at(path, std::fs::read_to_string(path)) {
+            contents.push((path.to_path_buf(), s));
+        }
+    }
+    errs.into_result_with(contents)
+}
+
+/// Remove every file, reporting every failure.
+pub fn remove_all<I, P>(paths: I) -> Result<(), ErrorVec<PathError<io::Error>>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let mut errs = ErrorVec::default();
+    for path in paths {
+        let path = path.as_ref();
+        errs.take_error_at(path, std::fs::remove_file(path));
+    }
+    errs.into_result()
+}
+
+/// Copy each `(from, to)` pair, returning the bytes copied per pair in order, or every failure.
+///
+/// Like [std::fs::copy], each copy carries over the permissions of `from`. Each failure is
+/// attributed to the path of the step which failed: opening or reading `from`, or creating,
+/// writing, or setting the permissions of `to`:
+///
+/// ```
+/// use errorvec::fs::copy_all;
+///
+/// let dir = std::env::temp_dir().join("errorvec-copy-all-doctest");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let present = dir.join("present.txt");
+/// std::fs::write(&present, "hello").unwrap();
+/// let missing = dir.join("missing.txt");
+/// let unwritable = dir.join("no-such-dir").join("copy.txt");
+///
+/// let errs = copy_all([
+///     (&missing, &dir.join("copy.txt")),
+///     (&present, &unwritable),
+///     (&dir, &dir.join("dir-copy")),
+/// ])
+/// .unwrap_err();
+/// assert_eq!(missing, errs[0].path);
+/// assert_eq!(unwritable, errs[1].path);
+/// assert_eq!(dir, errs[2].path);
+///
+/// assert_eq!(vec![5], copy_all([(&present, &dir.join("copy.txt"))]).unwrap());
+/// ```
+pub fn copy_all<I, P, Q>(pairs: I) -> Result<Vec<u64>, ErrorVec<PathError<io::Error>>>
+where
+    I: IntoIterator<Item = (P, Q)>,
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    pairs
+        .into_iter()
+        .map(|(from, to)| copy(from.as_ref(), to.as_ref()))
+        .into_errorvec_result()
+}
+
+fn copy(from: &Path, to: &Path) -> Result<u64, PathError<io::Error>> {
+    let at_from = |e| PathError::new(from, e);
+    let at_to = |e| PathError::new(to, e);
+
+    let mut reader = File::open(from).map_err(at_from)?;
+    let permissions = reader.metadata().map_err(at_from)?.permissions();
+    let mut writer = File::create(to).map_err(at_to)?;
+
+    let mut buf = [0; 8192];
+    let mut copied = 0;
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(at_from(e)),
+        };
+        writer.write_all(&buf[..n]).map_err(at_to)?;
+        copied += n as u64;
+    }
+    writer.set_permissions(permissions).map_err(at_to)?;
+    Ok(copied)
+}
@@ -0,0 +1,56 @@
+use crate::{ErrorVec, ReportEntry};
+use async_graphql::{ErrorExtensionValues, Response, ServerError};
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Convert each entry into its own [async_graphql::ServerError].
+    ///
+    /// Each error's `extensions` carry its 1-based `index`, plus its [ReportEntry::key] and
+    /// [ReportEntry::metadata] when present:
+    ///
+    /// ```
+    /// use async_graphql::Value;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["name is required", "age must be positive"].into_iter().collect();
+    /// let errors = ev.to_graphql_errors();
+    ///
+    /// assert_eq!(2, errors.len());
+    /// assert_eq!("age must be positive", errors[1].message);
+    /// assert_eq!(
+    ///     Some(&Value::from(2)),
+    ///     errors[1].extensions.as_ref().unwrap().get("index"),
+    /// );
+    /// ```
+    pub fn to_graphql_errors(&self) -> Vec<ServerError> {
+        self.iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set("index", i + 1);
+                if let Some(key) = e.key() {
+                    extensions.set("key", key);
+                }
+                for (name, value) in e.metadata() {
+                    extensions.set(name, value);
+                }
+
+                let mut err = ServerError::new(e.to_string().trim_end(), None);
+                err.extensions = Some(extensions);
+                err
+            })
+            .collect()
+    }
+}
+
+/// A GraphQL [Response] with no data and one entry per error in its `errors` array.
+impl<E> From<ErrorVec<E>> for Response
+where
+    E: ReportEntry,
+{
+    fn from(ev: ErrorVec<E>) -> Self {
+        Response::from_errors(ev.to_graphql_errors())
+    }
+}
@@ -0,0 +1,243 @@
+use crate::strings::{FmtLocalized, Localized};
+use crate::{Context, English, ErrorVec, MultiError, ReportStrings};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Errors accumulated into groups by key, such as per input file or per tenant.
+///
+/// `Display` renders one section per key, in key order:
+///
+/// ```
+/// use errorvec::GroupedErrorVec;
+///
+/// let mut errs = GroupedErrorVec::default();
+/// errs.push("users.csv", "line 3: missing email");
+/// errs.push("orders.csv", "line 9: unknown user");
+/// errs.push("users.csv", "line 7: duplicate id");
+///
+/// assert_eq!(3, errs.len());
+/// assert_eq!(vec![(&"orders.csv", 1), (&"users.csv", 2)], errs.counts().collect::<Vec<_>>());
+///
+/// let expected_display = r#"
+/// orders.csv: 1 error
+/// [error 1 of 1] line 9: unknown user
+///
+/// users.csv: 2 errors
+/// [error 1 of 2] line 3: missing email
+///
+/// [error 2 of 2] line 7: duplicate id
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+/// ```
+#[derive(Debug)]
+pub struct GroupedErrorVec<K, E> {
+    groups: BTreeMap<K, ErrorVec<E>>,
+}
+
+impl<K, E> Default for GroupedErrorVec<K, E> {
+    fn default() -> Self {
+        GroupedErrorVec {
+            groups: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K, E> GroupedErrorVec<K, E>
+where
+    K: Ord,
+{
+    /// Record `e` in the group for `key`.
+    pub fn push(&mut self, key: K, e: E) {
+        self.groups.entry(key).or_default().push(e);
+    }
+
+    /// As [ErrorVec::take_error], recording any error in the group for `key`.
+    pub fn take_error<T>(&mut self, key: K, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(key, e)).ok()
+    }
+
+    /// The errors of the group for `key`, if any.
+    pub fn group(&self, key: &K) -> Option<&ErrorVec<E>> {
+        self.groups.get(key)
+    }
+
+    /// Remove the group for `key`, returning `Ok(())` if it had no errors.
+    pub fn take_group_result(&mut self, key: &K) -> Result<(), ErrorVec<E>> {
+        self.groups.remove(key).unwrap_or_default().into_result()
+    }
+
+    /// Each key with its number of errors, in key order.
+    pub fn counts(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.groups.iter().map(|(k, ev)| (k, ev.len()))
+    }
+
+    /// The total number of errors across all groups.
+    pub fn len(&self) -> usize {
+        self.groups.values().map(|ev| ev.len()).sum()
+    }
+
+    /// True when no group has any errors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `Ok(())` if there are no errors, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Convert into the underlying map of groups.
+    pub fn into_groups(self) -> BTreeMap<K, ErrorVec<E>> {
+        self.groups
+    }
+}
+
+impl<K, E> GroupedErrorVec<K, E>
+where
+    K: Ord + Clone,
+{
+    /// Flatten into a single [ErrorVec] in key order, with each error given its key as
+    /// [Context].
+    pub fn into_errorvec(self) -> ErrorVec<Context<K, E>> {
+        self.groups
+            .into_iter()
+            .flat_map(|(k, ev)| ev.into_iter().map(move |e| Context::new(k.clone(), e)))
+            .collect()
+    }
+
+    /// Flatten into a single [ErrorVec] as [GroupedErrorVec::into_errorvec], but without
+    /// consuming `self`.
+    pub fn to_errorvec(&self) -> ErrorVec<Context<K, &E>> {
+        self.groups
+            .iter()
+            .flat_map(|(k, ev)| ev.iter().map(move |e| Context::new(k.clone(), e)))
+            .collect()
+    }
+}
+
+impl<K, E> From<ErrorVec<Context<K, E>>> for GroupedErrorVec<K, E>
+where
+    K: Ord,
+{
+    fn from(ev: ErrorVec<Context<K, E>>) -> Self {
+        let mut grouped = GroupedErrorVec::default();
+        for c in ev {
+            grouped.push(c.context, c.error);
+        }
+        grouped
+    }
+}
+
+impl<E> ErrorVec<E> {
+    /// Split into groups keyed by `key`, preserving order within each group:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["a: 1", "b: 2", "a: 3"].into_iter().collect();
+    /// let grouped = ev.into_grouped(|e| e.split(':').next().unwrap());
+    ///
+    /// assert_eq!(&["a: 1", "a: 3"], grouped.group(&"a").unwrap().as_slice());
+    /// ```
+    pub fn into_grouped<K, F>(self, key: F) -> GroupedErrorVec<K, E>
+    where
+        K: Ord,
+        F: Fn(&E) -> K,
+    {
+        let mut grouped = GroupedErrorVec::default();
+        for e in self {
+            grouped.push(key(&e), e);
+        }
+        grouped
+    }
+}
+
+/// Errors are iterated in key order, and [MultiError::into_errors] discards the keys; see
+/// [GroupedErrorVec::into_errorvec] to keep them.
+impl<K, E> MultiError for GroupedErrorVec<K, E>
+where
+    K: Ord,
+{
+    type Error = E;
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &E> {
+        self.groups.values().flat_map(|ev| ev.iter())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (k, ev) in other.groups {
+            self.groups.entry(k).or_default().extend(ev);
+        }
+    }
+
+    fn into_errors(self) -> ErrorVec<E> {
+        self.groups.into_values().flatten().collect()
+    }
+}
+
+impl<K, E> fmt::Display for GroupedErrorVec<K, E>
+where
+    K: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<K, E> GroupedErrorVec<K, E>
+where
+    K: fmt::Display,
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including each group's
+    /// [ReportStrings::error_count].
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<K, E> FmtLocalized for GroupedErrorVec<K, E>
+where
+    K: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        if self.groups.is_empty() {
+            return ErrorVec::<E>::default().fmt_localized(f, strings);
+        }
+        for (i, (k, ev)) in self.groups.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}: {}", k, strings.error_count(ev.len()))?;
+            ev.fmt_localized(f, strings)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, E> std::error::Error for GroupedErrorVec<K, E>
+where
+    K: fmt::Display + fmt::Debug,
+    E: fmt::Display + fmt::Debug,
+{
+}
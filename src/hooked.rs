@@ -0,0 +1,129 @@
+use crate::ErrorVec;
+use std::fmt;
+use std::ops::Deref;
+
+/// An [ErrorVec] which calls observer hooks the moment each error is recorded, for side effects
+/// such as metrics, logging, or circuit breakers.
+///
+/// Each hook receives the new error and the number of errors recorded so far, including it.
+/// Hooks run in registration order. Only shared access to the inner [ErrorVec] is provided, so
+/// that every error passes through the hooks:
+///
+/// ```
+/// use std::cell::Cell;
+/// use errorvec::HookedErrorVec;
+///
+/// let tripped = Cell::new(false);
+/// let mut log = vec![];
+/// let mut errs = HookedErrorVec::default()
+///     .on_push(|e: &String, count| log.push(format!("#{count}: {e}")))
+///     .on_push(|_, count| tripped.set(count >= 2));
+///
+/// errs.take_error("x".parse::<u32>().map_err(|e| e.to_string()));
+/// assert!(!tripped.get());
+/// errs.push("connection reset".to_string());
+/// assert!(tripped.get());
+///
+/// let ev = errs.into_errorvec();
+/// assert_eq!(2, ev.len());
+/// assert_eq!("#2: connection reset", log[1]);
+/// ```
+pub struct HookedErrorVec<'a, E> {
+    ev: ErrorVec<E>,
+    hooks: Vec<Hook<'a, E>>,
+}
+
+type Hook<'a, E> = Box<dyn FnMut(&E, usize) + 'a>;
+
+impl<E> Default for HookedErrorVec<'_, E> {
+    fn default() -> Self {
+        HookedErrorVec {
+            ev: ErrorVec::default(),
+            hooks: vec![],
+        }
+    }
+}
+
+impl<'a, E> HookedErrorVec<'a, E> {
+    /// Register `hook` to be called on every recorded error.
+    pub fn on_push<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut(&E, usize) + 'a,
+    {
+        self.add_hook(hook);
+        self
+    }
+
+    /// As [HookedErrorVec::on_push], without consuming `self`.
+    pub fn add_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&E, usize) + 'a,
+    {
+        self.hooks.push(Box::new(hook));
+    }
+
+    /// Record `e`, then call each hook.
+    pub fn push(&mut self, e: E) {
+        self.ev.push(e);
+        let count = self.ev.len();
+        let e = &self.ev[count - 1];
+        for hook in &mut self.hooks {
+            hook(e, count);
+        }
+    }
+
+    /// As [ErrorVec::take_error], calling each hook on any error.
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Discard the hooks.
+    pub fn into_errorvec(self) -> ErrorVec<E> {
+        self.ev
+    }
+
+    /// Discard the hooks, then [ErrorVec::into_result].
+    pub fn into_result(self) -> Result<(), ErrorVec<E>> {
+        self.ev.into_result()
+    }
+}
+
+impl<E> Extend<E> for HookedErrorVec<'_, E> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+    }
+}
+
+impl<E> Deref for HookedErrorVec<'_, E> {
+    type Target = ErrorVec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ev
+    }
+}
+
+impl<E> fmt::Debug for HookedErrorVec<'_, E>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HookedErrorVec")
+            .field("ev", &self.ev)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl<E> fmt::Display for HookedErrorVec<'_, E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.ev.fmt(f)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::markup::xml_escape;
+use crate::{English, ErrorVec, ReportEntry, ReportStrings, Severity};
+use std::io;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+summary { cursor: pointer; font-weight: bold; }
+pre { white-space: pre-wrap; margin: 0.25em 0; }
+dl { display: grid; grid-template-columns: max-content auto; gap: 0 1em; margin: 0 0 1em; }
+dt { color: #666; }
+.fatal summary, .error summary { color: #b00; }
+.warning summary { color: #a60; }
+.note summary, .hint summary { color: #068; }";
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Write a standalone HTML page titled `title` to `w`, with the entries in one collapsible
+    /// group per [ReportEntry::severity], most severe first.
+    ///
+    /// Each entry keeps its `[error K of N]` number and lists its key, code, span, metadata, and
+    /// source chain when present. Nested entries are listed beneath their parent:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Leveled, Severity};
+    ///
+    /// let ev: ErrorVec<_> = [
+    ///     Leveled::new(Severity::Warning, "unused <import>"),
+    ///     Leveled::new(Severity::Error, "missing field `id`"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut out = vec![];
+    /// ev.write_html_report(&mut out, "nightly import").unwrap();
+    /// let html = String::from_utf8(out).unwrap();
+    ///
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// assert!(html.contains("<h1>nightly import</h1>\n<p>2 errors</p>"));
+    /// assert!(html.contains(r#"<details class="error" open><summary>error (1)</summary>"#));
+    /// assert!(html.contains(r#"<li value="1"><pre>warning: unused &lt;import&gt;</pre>"#));
+    /// ```
+    pub fn write_html_report<W>(&self, w: W, title: &str) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_html_report_localized(w, title, &English)
+    }
+
+    /// As [ErrorVec::write_html_report], with the page language, counts, severity names, and
+    /// cause labels from `strings`.
+    pub fn write_html_report_localized<W, S>(
+        &self,
+        mut w: W,
+        title: &str,
+        strings: &S,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        S: ReportStrings + ?Sized,
+    {
+        let title = xml_escape(title);
+        writeln!(w, "<!DOCTYPE html>")?;
+        writeln!(w, r#"<html lang="{}">"#, xml_escape(&strings.language()))?;
+        writeln!(w, "<head>")?;
+        writeln!(w, r#"<meta charset="utf-8">"#)?;
+        writeln!(w, "<title>{}</title>", title)?;
+        writeln!(w, "<style>\n{}\n</style>", STYLE)?;
+        writeln!(w, "</head>")?;
+        writeln!(w, "<body>")?;
+        writeln!(w, "<h1>{}</h1>", title)?;
+        writeln!(w, "<p>{}</p>", xml_escape(&strings.error_count(self.len())))?;
+
+        let levels = [
+            Severity::Fatal,
+            Severity::Error,
+            Severity::Warning,
+            Severity::Note,
+            Severity::Hint,
+        ];
+        for level in levels {
+            let entries: Vec<(usize, &E)> = self
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.severity() == level)
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+            let open = if level >= Severity::Error {
+                " open"
+            } else {
+                ""
+            };
+            writeln!(
+                w,
+                r#"<details class="{}"{}><summary>{} ({})</summary>"#,
+                level,
+                open,
+                xml_escape(&strings.severity(level)),
+                entries.len()
+            )?;
+            writeln!(w, "<ol>")?;
+            for (i, e) in entries {
+                write_entry(&mut w, strings, Some(i + 1), e)?;
+            }
+            writeln!(w, "</ol>")?;
+            writeln!(w, "</details>")?;
+        }
+
+        writeln!(w, "</body>")?;
+        writeln!(w, "</html>")?;
+        Ok(())
+    }
+}
+
+fn write_entry<W, S>(
+    w: &mut W,
+    strings: &S,
+    number: Option<usize>,
+    e: &dyn ReportEntry,
+) -> io::Result<()>
+where
+    W: io::Write,
+    S: ReportStrings + ?Sized,
+{
+    match number {
+        Some(n) => write!(w, r#"<li value="{}">"#, n)?,
+        None => write!(w, "<li>")?,
+    }
+    writeln!(w, "<pre>{}</pre>", xml_escape(e.to_string().trim_end()))?;
+
+    let mut details: Vec<(String, String)> = vec![];
+    details.extend(e.key().map(|k| ("key".to_string(), k)));
+    details.extend(e.code().map(|c| ("code".to_string(), c)));
+    details.extend(e.span().map(|s| ("span".to_string(), s.to_string())));
+    details.extend(e.metadata());
+    details.extend(
+        e.source_chain()
+            .into_iter()
+            .map(|s| (strings.caused_by(), s)),
+    );
+    if !details.is_empty() {
+        writeln!(w, "<dl>")?;
+        for (name, value) in details {
+            writeln!(
+                w,
+                "<dt>{}</dt><dd>{}</dd>",
+                xml_escape(&name),
+                xml_escape(&value)
+            )?;
+        }
+        writeln!(w, "</dl>")?;
+    }
+
+    let children = e.children();
+    if !children.is_empty() {
+        writeln!(w, "<ul>")?;
+        for child in children {
+            write_entry(w, strings, None, child)?;
+        }
+        writeln!(w, "</ul>")?;
+    }
+    writeln!(w, "</li>")
+}
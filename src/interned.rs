@@ -0,0 +1,292 @@
+use crate::entry::forward_report_entry;
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, ReportEntry, ReportStrings};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A compact alternative to [ErrorVec] for huge runs, storing errors with identical rendered
+/// messages once along with how often they occurred.
+///
+/// Only the first few occurrences of each message are kept as examples, so memory grows with the
+/// number of distinct messages rather than the number of errors:
+///
+/// ```
+/// use errorvec::InternedErrorVec;
+///
+/// let mut errs = InternedErrorVec::default();
+/// for page in 0..1000 {
+///     if page % 2 == 0 {
+///         errs.push("connection reset");
+///     }
+/// }
+/// errs.push("robots.txt disallows /admin");
+///
+/// assert_eq!(501, errs.len());
+/// assert_eq!(2, errs.distinct());
+///
+/// let expected_display = r#"
+/// [error 1 of 2] connection reset (500 occurrences)
+///
+/// [error 2 of 2] robots.txt disallows /admin
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+/// ```
+#[derive(Debug)]
+pub struct InternedErrorVec<E> {
+    entries: Vec<InternedEntry<E>>,
+    index: HashMap<String, usize>,
+    max_examples: usize,
+    total: usize,
+}
+
+/// One distinct error of an [InternedErrorVec], with its occurrence count and first examples.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedEntry<E> {
+    /// How many times the error occurred.
+    pub count: usize,
+    examples: Vec<E>,
+}
+
+impl<E> Default for InternedErrorVec<E> {
+    fn default() -> Self {
+        InternedErrorVec::with_max_examples(1)
+    }
+}
+
+impl<E> InternedErrorVec<E> {
+    /// Keep up to `max_examples` occurrences of each distinct error, and at least one.
+    pub fn with_max_examples(max_examples: usize) -> Self {
+        InternedErrorVec {
+            entries: vec![],
+            index: HashMap::new(),
+            max_examples: max_examples.max(1),
+            total: 0,
+        }
+    }
+
+    /// Record `e` under `key`, returning true if `key` had not been seen before.
+    pub fn push_by_key(&mut self, key: String, e: E) -> bool {
+        self.total += 1;
+        match self.index.get(&key) {
+            Some(&i) => {
+                let entry = &mut self.entries[i];
+                entry.count += 1;
+                if entry.examples.len() < self.max_examples {
+                    entry.examples.push(e);
+                }
+                false
+            }
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(InternedEntry {
+                    count: 1,
+                    examples: vec![e],
+                });
+                true
+            }
+        }
+    }
+
+    /// The total number of errors recorded, counting repeats.
+    pub fn len(&self) -> usize {
+        self.total
+    }
+
+    /// True when no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// The number of distinct errors.
+    pub fn distinct(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The distinct errors, in order of first occurrence.
+    pub fn iter(&self) -> impl Iterator<Item = &InternedEntry<E>> {
+        self.entries.iter()
+    }
+
+    /// Convert into an [ErrorVec] of the distinct errors.
+    pub fn into_errorvec(self) -> ErrorVec<InternedEntry<E>> {
+        ErrorVec::from(self.entries)
+    }
+
+    /// `Ok(())` if no errors were recorded, otherwise `Err` of the distinct errors.
+    pub fn into_result(self) -> Result<(), ErrorVec<InternedEntry<E>>> {
+        self.into_errorvec().into_result()
+    }
+}
+
+impl<E> InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Record `e`, keyed by its rendered message, returning true if the message is new.
+    pub fn push(&mut self, e: E) -> bool {
+        self.push_by_key(e.to_string(), e)
+    }
+
+    /// As [ErrorVec::take_error], interning the error.
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        r.map_err(|e| self.push(e)).ok()
+    }
+}
+
+impl<E> Extend<E> for InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+    }
+}
+
+impl<E> FromIterator<E> for InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let mut errs = InternedErrorVec::default();
+        errs.extend(iter);
+        errs
+    }
+}
+
+impl<E> fmt::Display for InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<E> InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Display the report with the framing text from `strings`, including
+    /// [ReportStrings::occurrences] for repeated messages.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<E> FmtLocalized for InternedErrorVec<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        self.iter()
+            .map(|value| Localized { value, strings })
+            .collect::<ErrorVec<_>>()
+            .fmt_localized(f, strings)
+    }
+}
+
+impl<E> InternedEntry<E> {
+    /// The first occurrence.
+    pub fn example(&self) -> &E {
+        &self.examples[0]
+    }
+
+    /// The first occurrences, oldest first. Never empty:
+    ///
+    /// ```
+    /// use errorvec::InternedErrorVec;
+    ///
+    /// let mut errs = InternedErrorVec::with_max_examples(2);
+    /// for _ in 0..3 {
+    ///     errs.push("connection reset");
+    /// }
+    ///
+    /// let entry = errs.iter().next().unwrap();
+    /// assert_eq!(3, entry.count);
+    /// assert_eq!(["connection reset"; 2], entry.examples());
+    /// ```
+    pub fn examples(&self) -> &[E] {
+        &self.examples
+    }
+}
+
+impl<E> fmt::Display for InternedEntry<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<E> InternedEntry<E>
+where
+    E: fmt::Display,
+{
+    /// Display the entry with [ReportStrings::occurrences] from `strings` if it repeated.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<E> FmtLocalized for InternedEntry<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        self.example().fmt(f)?;
+        if self.count > 1 {
+            write!(f, " {}", strings.occurrences(self.count))?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> std::error::Error for InternedEntry<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.example().source()
+    }
+}
+
+impl<E> ReportEntry for InternedEntry<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(example(); message, key, source_chain, span, severity, children, code);
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = self.example().metadata();
+        metadata.push(("occurrences".to_string(), self.count.to_string()));
+        metadata
+    }
+}
@@ -0,0 +1,53 @@
+use crate::ErrorVec;
+use std::io;
+
+impl ErrorVec<io::Error> {
+    /// Combine into a single [io::Error] wrapping the full report.
+    ///
+    /// The result's [io::ErrorKind] is the most common kind among the errors, with ties going to
+    /// the kind that appears first:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<io::Error> = [
+    ///     io::ErrorKind::PermissionDenied,
+    ///     io::ErrorKind::NotFound,
+    ///     io::ErrorKind::NotFound,
+    /// ]
+    /// .into_iter()
+    /// .map(io::Error::from)
+    /// .collect();
+    ///
+    /// let e = ev.into_io_error();
+    /// assert_eq!(io::ErrorKind::NotFound, e.kind());
+    /// assert!(e.to_string().starts_with("[error 1 of 3] permission denied"));
+    /// ```
+    pub fn into_io_error(self) -> io::Error {
+        let mut counts: Vec<(io::ErrorKind, usize)> = vec![];
+        for e in self.iter() {
+            match counts.iter_mut().find(|(k, _)| *k == e.kind()) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((e.kind(), 1)),
+            }
+        }
+
+        let mut kind = io::ErrorKind::Other;
+        let mut best = 0;
+        for (k, n) in counts {
+            if n > best {
+                kind = k;
+                best = n;
+            }
+        }
+
+        io::Error::new(kind, self)
+    }
+}
+
+impl From<ErrorVec<io::Error>> for io::Error {
+    fn from(ev: ErrorVec<io::Error>) -> Self {
+        ev.into_io_error()
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{ErrorVec, ReportEntry};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+
+/// The machine-readable form of an [ErrorVec], produced by [ErrorVec::to_json_report].
+///
+/// The serialized schema is:
+///
+/// ```json
+/// {
+///   "count": 2,
+///   "errors": [
+///     {
+///       "index": 1,
+///       "key": "...",
+///       "code": "...",
+///       "message": "...",
+///       "sources": ["..."],
+///       "metadata": { "name": "value" }
+///     },
+///     { "index": 2, "message": "..." }
+///   ]
+/// }
+/// ```
+///
+/// `key`, `code`, `sources`, and `metadata` are omitted when empty.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct JsonReport {
+    /// The number of entries in `errors`.
+    pub count: usize,
+    /// One record per error, in order.
+    pub errors: Vec<JsonEntry>,
+}
+
+/// A single error within a [JsonReport].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct JsonEntry {
+    /// The 1-based position of this error, matching the `[error K of N]` numbering of `Display`.
+    pub index: usize,
+    /// The [ReportEntry::key] of the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The [ReportEntry::code] of the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The `Display` text of the error.
+    pub message: String,
+    /// The [ReportEntry::source_chain] of the error.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+    /// The [ReportEntry::metadata] of the error.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl JsonEntry {
+    /// Build the record for `e` at 1-based position `index`.
+    pub fn new<E>(index: usize, e: &E) -> Self
+    where
+        E: ReportEntry,
+    {
+        JsonEntry {
+            index,
+            key: e.key(),
+            code: e.code(),
+            message: e.to_string().trim_end().to_string(),
+            sources: e.source_chain(),
+            metadata: e.metadata().into_iter().collect(),
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Build the [JsonReport] describing every error.
+    pub fn to_json_report(&self) -> JsonReport {
+        JsonReport {
+            count: self.len(),
+            errors: self
+                .iter()
+                .enumerate()
+                .map(|(i, e)| JsonEntry::new(i + 1, e))
+                .collect(),
+        }
+    }
+
+    /// Render the [JsonReport] as a [serde_json::Value]:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops", "ouch!"].into_iter().collect();
+    /// assert_eq!(
+    ///     ev.to_json(),
+    ///     serde_json::json!({
+    ///         "count": 2,
+    ///         "errors": [
+    ///             { "index": 1, "message": "whoops" },
+    ///             { "index": 2, "message": "ouch!" },
+    ///         ],
+    ///     }),
+    /// );
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.to_json_report()).expect("a JsonReport is always valid json")
+    }
+
+    /// Write one [JsonEntry] per line to `w`, as newline-delimited JSON:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops", "ouch!"].into_iter().collect();
+    /// let mut out = vec![];
+    /// ev.write_ndjson(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "{\"index\":1,\"message\":\"whoops\"}\n{\"index\":2,\"message\":\"ouch!\"}\n",
+    /// );
+    /// ```
+    pub fn write_ndjson<W>(&self, mut w: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        for (i, e) in self.iter().enumerate() {
+            serde_json::to_writer(&mut w, &JsonEntry::new(i + 1, e))?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `Display` text is the `message`.
+impl fmt::Display for JsonEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for JsonEntry {}
+
+/// Restores the key, code, sources, and metadata of the original entry, so that a deserialized
+/// report renders as the original did.
+impl ReportEntry for JsonEntry {
+    fn key(&self) -> Option<String> {
+        self.key.clone()
+    }
+
+    fn source_chain(&self) -> Vec<String> {
+        self.sources.clone()
+    }
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        self.metadata
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    fn code(&self) -> Option<String> {
+        self.code.clone()
+    }
+}
+
+impl From<JsonReport> for ErrorVec<JsonEntry> {
+    fn from(report: JsonReport) -> Self {
+        report.errors.into()
+    }
+}
+
+impl JsonReport {
+    /// Combine reports from several origins, such as build workers, into one. Each entry gains an
+    /// `origin` metadata value naming its report, and entries are renumbered in order:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, JsonReport};
+    ///
+    /// let a: ErrorVec<&str> = ["link failed"].into_iter().collect();
+    /// let b: ErrorVec<&str> = ["test timed out"].into_iter().collect();
+    ///
+    /// let merged = JsonReport::merge([("worker-a", a.to_json_report()), ("worker-b", b.to_json_report())]);
+    /// assert_eq!(
+    ///     serde_json::to_value(&merged).unwrap(),
+    ///     serde_json::json!({
+    ///         "count": 2,
+    ///         "errors": [
+    ///             { "index": 1, "message": "link failed", "metadata": { "origin": "worker-a" } },
+    ///             { "index": 2, "message": "test timed out", "metadata": { "origin": "worker-b" } },
+    ///         ],
+    ///     }),
+    /// );
+    /// ```
+    pub fn merge<I, S>(reports: I) -> JsonReport
+    where
+        I: IntoIterator<Item = (S, JsonReport)>,
+        S: Into<String>,
+    {
+        let errors: Vec<JsonEntry> = reports
+            .into_iter()
+            .flat_map(|(origin, report)| {
+                let origin = origin.into();
+                report.errors.into_iter().map(move |mut entry| {
+                    entry.metadata.insert("origin".to_string(), origin.clone());
+                    entry
+                })
+            })
+            .enumerate()
+            .map(|(i, entry)| JsonEntry {
+                index: i + 1,
+                ..entry
+            })
+            .collect();
+
+        JsonReport {
+            count: errors.len(),
+            errors,
+        }
+    }
+}
+
+impl ErrorVec<JsonEntry> {
+    /// Read a serialized [JsonReport] from each named reader and merge them as
+    /// [JsonReport::merge] does:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, ReportEntry};
+    ///
+    /// let worker_a = r#"{"count":1,"errors":[{"index":1,"message":"link failed"}]}"#;
+    /// let worker_b = r#"{"count":1,"errors":[{"index":1,"code":"E1","message":"bad flag"}]}"#;
+    ///
+    /// let ev = ErrorVec::merge_from_readers([("a", worker_a.as_bytes()), ("b", worker_b.as_bytes())])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, ev.len());
+    /// assert_eq!(Some("E1".to_string()), ev[1].code());
+    /// assert_eq!(vec![("origin".to_string(), "b".to_string())], ev[1].metadata());
+    /// ```
+    pub fn merge_from_readers<I, S, R>(readers: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = (S, R)>,
+        S: Into<String>,
+        R: io::Read,
+    {
+        let reports = readers
+            .into_iter()
+            .map(|(origin, r)| Ok((origin, serde_json::from_reader(r)?)))
+            .collect::<io::Result<Vec<(S, JsonReport)>>>()?;
+        Ok(JsonReport::merge(reports).into())
+    }
+}
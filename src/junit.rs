@@ -0,0 +1,82 @@
+use crate::markup::xml_escape;
+use crate::{ErrorVec, ReportEntry};
+use std::io;
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Write a JUnit XML `<testsuite>` named `suite_name` to `w`, with one failed `<testcase>` per
+    /// entry.
+    ///
+    /// Each test case is named by the entry's [ReportEntry::key], falling back to `error K`:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["expected 3, found <4>"].into_iter().collect();
+    /// let mut out = vec![];
+    /// ev.write_junit(&mut out, "checks").unwrap();
+    ///
+    /// let expected = r#"
+    /// <?xml version="1.0" encoding="UTF-8"?>
+    /// <testsuite name="checks" tests="1" failures="1" errors="0">
+    ///   <testcase classname="checks" name="error 1">
+    ///     <failure message="expected 3, found &lt;4&gt;">expected 3, found &lt;4&gt;</failure>
+    ///   </testcase>
+    /// </testsuite>
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected, String::from_utf8(out).unwrap());
+    /// ```
+    ///
+    /// Characters XML 1.0 forbids, such as the escapes of colored error text, become U+FFFD so
+    /// the report stays well-formed:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["\u{1b}[31mfailed\u{1b}[0m"].into_iter().collect();
+    /// let mut out = vec![];
+    /// ev.write_junit(&mut out, "checks").unwrap();
+    ///
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert!(!out.contains('\u{1b}'));
+    /// assert!(out.contains("<failure message=\"\u{fffd}[31mfailed\u{fffd}[0m\">"));
+    /// ```
+    pub fn write_junit<W>(&self, mut w: W, suite_name: &str) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let suite = xml_escape(suite_name);
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            r#"<testsuite name="{}" tests="{}" failures="{}" errors="0">"#,
+            suite,
+            self.len(),
+            self.len()
+        )?;
+        for (i, e) in self.iter().enumerate() {
+            let name = e.key().unwrap_or_else(|| format!("error {}", i + 1));
+            let text = e.to_string();
+            let text = text.trim_end();
+            let summary = text.lines().next().unwrap_or("");
+            writeln!(
+                w,
+                r#"  <testcase classname="{}" name="{}">"#,
+                suite,
+                xml_escape(&name)
+            )?;
+            writeln!(
+                w,
+                r#"    <failure message="{}">{}</failure>"#,
+                xml_escape(summary),
+                xml_escape(text)
+            )?;
+            writeln!(w, "  </testcase>")?;
+        }
+        writeln!(w, "</testsuite>")?;
+        Ok(())
+    }
+}
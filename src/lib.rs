@@ -1,7 +1,248 @@
 //! Utilities for tracking multiple errors.
+//!
+//! Everything but [BoundedErrorVec] requires the default `std` feature. Without it, the crate is
+//! `no_std` and never allocates.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
+#[cfg(feature = "allocator_api")]
+mod alloc;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "axum")]
+mod axum;
+mod bounded;
+#[cfg(feature = "std")]
+mod cancel;
+#[cfg(feature = "std")]
+mod capped;
+#[cfg(feature = "std")]
+mod channel;
+#[cfg(feature = "std")]
+mod checked;
+#[cfg(feature = "std")]
+mod checkpoint;
+#[cfg(feature = "std")]
+mod chunks;
+#[cfg(feature = "std")]
+mod coded;
+#[cfg(feature = "codespan-reporting")]
+mod codespan;
+#[cfg(feature = "color")]
+mod color;
+#[cfg(feature = "std")]
+mod context;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "std")]
+mod dedup;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(feature = "std")]
+mod entry;
+#[cfg(feature = "error-stack")]
+mod errorstack;
+#[cfg(feature = "std")]
 mod errorvec;
+#[cfg(feature = "std")]
+mod expect;
+#[cfg(feature = "eyre")]
+mod eyre;
+#[cfg(feature = "std")]
+mod fatal;
+#[cfg(feature = "std")]
+mod filtermap;
+#[cfg(feature = "std")]
+mod format;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "std")]
+mod grouped;
+#[cfg(feature = "std")]
+mod hooked;
+#[cfg(feature = "html")]
+mod html;
+#[cfg(feature = "std")]
+mod interned;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "junit")]
+mod junit;
+#[cfg(feature = "lsp-types")]
+mod lsp;
+#[cfg(feature = "std")]
+mod macros;
+#[cfg(feature = "std")]
+mod map;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(any(feature = "html", feature = "junit"))]
+mod markup;
+#[cfg(feature = "std")]
+mod message;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "std")]
+mod multi;
+#[cfg(feature = "serde_json")]
+mod multistatus;
+#[cfg(feature = "std")]
+mod ordered;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "serde_json")]
+mod problem;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "std")]
+mod redact;
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
 mod resiter;
+#[cfg(feature = "std")]
+mod resultext;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(feature = "std")]
+mod running;
+#[cfg(feature = "sarif")]
+mod sarif;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "std")]
+mod scoped;
+#[cfg(feature = "sentry")]
+mod sentry;
+#[cfg(feature = "std")]
+mod severity;
+#[cfg(feature = "std")]
+mod slice;
+#[cfg(feature = "snafu")]
+mod snafu;
+#[cfg(feature = "std")]
+mod spanned;
+#[cfg(feature = "spill")]
+mod spill;
+#[cfg(feature = "std")]
+mod strings;
+#[cfg(feature = "std")]
+mod summary;
+#[cfg(feature = "std")]
+mod tagged;
+#[cfg(feature = "tonic")]
+mod tonic;
+#[cfg(feature = "std")]
+mod tree;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "std")]
+mod wrap;
 
+#[cfg(feature = "allocator_api")]
+pub use self::alloc::ErrorVecIn;
+pub use self::bounded::BoundedErrorVec;
+#[cfg(feature = "std")]
+pub use self::cancel::{CancelSignal, PartialOutcome};
+#[cfg(feature = "std")]
+pub use self::capped::{CappedErrorVec, Retention};
+#[cfg(feature = "async")]
+pub use self::channel::{async_error_channel, AsyncErrorCollector, AsyncErrorSender};
+#[cfg(feature = "std")]
+pub use self::channel::{error_channel, ErrorCollector, ErrorSender};
+#[cfg(feature = "std")]
+pub use self::checked::CheckedErrorVec;
+#[cfg(feature = "std")]
+pub use self::checkpoint::Checkpoint;
+#[cfg(feature = "std")]
+pub use self::chunks::{ChunkPolicy, ChunksGathering};
+#[cfg(feature = "std")]
+pub use self::coded::Coded;
+#[cfg(feature = "color")]
+pub use self::color::{ColorChoice, ColoredReport};
+#[cfg(feature = "std")]
+pub use self::context::Context;
+#[cfg(feature = "csv")]
+pub use self::csv::CSV_COLUMNS;
+#[cfg(feature = "std")]
+pub use self::dedup::DedupErrorVec;
+#[cfg(feature = "std")]
+pub use self::diff::ErrorVecDiff;
+#[cfg(feature = "std")]
+pub use self::entry::{error_source_chain, ReportEntry};
+#[cfg(feature = "std")]
 pub use self::errorvec::ErrorVec;
+#[cfg(feature = "std")]
+pub use self::expect::{ExpectationMismatch, ExpectedError};
+#[cfg(feature = "std")]
+pub use self::fatal::TruncatedErrorVec;
+#[cfg(feature = "std")]
+pub use self::filtermap::FilterMapGathering;
+#[cfg(feature = "std")]
+pub use self::format::{ErrorFormat, UnknownErrorFormat};
+#[cfg(feature = "std")]
+pub use self::grouped::GroupedErrorVec;
+#[cfg(feature = "std")]
+pub use self::hooked::HookedErrorVec;
+#[cfg(feature = "std")]
+pub use self::interned::{InternedEntry, InternedErrorVec};
+#[cfg(feature = "serde_json")]
+pub use self::json::{JsonEntry, JsonReport};
+#[cfg(feature = "std")]
+pub use self::map::{DuplicateKey, UniqueKeyMap};
+#[cfg(feature = "std")]
+pub use self::message::StringError;
+#[cfg(feature = "metrics")]
+pub use self::metrics::RecordErrorMetrics;
+#[cfg(feature = "std")]
+pub use self::multi::MultiError;
+#[cfg(feature = "serde_json")]
+pub use self::multistatus::{MultiStatus, MultiStatusItem};
+#[cfg(feature = "std")]
+pub use self::path::PathError;
+#[cfg(feature = "serde_json")]
+pub use self::problem::ProblemDetails;
+#[cfg(feature = "rayon")]
+pub use self::rayon::ParallelResultIterator;
+#[cfg(feature = "std")]
+pub use self::redact::Redacted;
+#[cfg(feature = "std")]
 pub use self::resiter::ResultIterator;
+#[cfg(feature = "std")]
+pub use self::resultext::ErrorVecResultExt;
+#[cfg(feature = "std")]
+pub use self::retry::{Attempt, RetryEntry, RetryQueue};
+#[cfg(feature = "std")]
+pub use self::running::RunningErrorVec;
+#[cfg(feature = "sarif")]
+pub use self::sarif::{SARIF_SCHEMA, SARIF_VERSION};
+#[cfg(feature = "std")]
+pub use self::scoped::Scoped;
+#[cfg(feature = "std")]
+pub use self::severity::{Leveled, Severity};
+#[cfg(feature = "std")]
+pub use self::slice::ErrorSliceDisplay;
+#[cfg(feature = "std")]
+pub use self::spanned::{LineCol, Span, Spanned};
+#[cfg(feature = "spill")]
+pub use self::spill::SpillingErrorVec;
+#[cfg(feature = "std")]
+pub use self::strings::{English, ReportStrings};
+#[cfg(feature = "std")]
+pub use self::summary::{ClassSummary, Summary};
+#[cfg(feature = "std")]
+pub use self::tagged::Tagged;
+#[cfg(feature = "std")]
+pub use self::tree::TreeReport;
+#[cfg(feature = "std")]
+pub use self::wrap::{WrappedReport, DEFAULT_WRAP_WIDTH};
@@ -0,0 +1,110 @@
+use crate::{ErrorVec, LineCol, ReportEntry, Severity};
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, NumberOrString, Position, PositionEncodingKind, Range,
+};
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Convert every entry into an [lsp_types::Diagnostic], ready for `textDocument/publishDiagnostics`.
+    ///
+    /// The range comes from the entry's [ReportEntry::span], converted to 0-based positions
+    /// within the document `text`, with characters counted in UTF-16 code units as LSP does by
+    /// default, or is empty at the start of the document without a span. The message comes from
+    /// [ReportEntry::message], the severity from [ReportEntry::severity], and the code from
+    /// [ReportEntry::code]:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Span, Spanned};
+    /// use lsp_types::{DiagnosticSeverity, Position};
+    ///
+    /// let text = "let 🦀 = foo;";
+    /// let ev: ErrorVec<_> = [Spanned::new(
+    ///     Span::from_bytes("main.ml", text, 11..14),
+    ///     "unknown identifier `foo`",
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    /// let diags = ev.to_lsp_diagnostics("mylang", text);
+    ///
+    /// assert_eq!(1, diags.len());
+    /// assert_eq!("unknown identifier `foo`", diags[0].message);
+    /// assert_eq!(Some(DiagnosticSeverity::ERROR), diags[0].severity);
+    /// assert_eq!(Some("mylang".to_string()), diags[0].source);
+    /// assert_eq!(Position::new(0, 9), diags[0].range.start);
+    /// ```
+    pub fn to_lsp_diagnostics(&self, source: &str, text: &str) -> Vec<Diagnostic> {
+        self.to_lsp_diagnostics_with_encoding(source, text, &PositionEncodingKind::UTF16)
+    }
+
+    /// As [ErrorVec::to_lsp_diagnostics], counting characters in the negotiated `encoding`.
+    /// Encodings other than UTF-8 and UTF-32 count UTF-16 code units:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Span, Spanned};
+    /// use lsp_types::{Position, PositionEncodingKind};
+    ///
+    /// let text = "let 🦀 = foo;";
+    /// let ev: ErrorVec<_> = [Spanned::new(Span::from_bytes("main.ml", text, 11..14), "unknown")]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let utf8 = ev.to_lsp_diagnostics_with_encoding("mylang", text, &PositionEncodingKind::UTF8);
+    /// let utf32 = ev.to_lsp_diagnostics_with_encoding("mylang", text, &PositionEncodingKind::UTF32);
+    /// assert_eq!(Position::new(0, 11), utf8[0].range.start);
+    /// assert_eq!(Position::new(0, 8), utf32[0].range.start);
+    /// ```
+    pub fn to_lsp_diagnostics_with_encoding(
+        &self,
+        source: &str,
+        text: &str,
+        encoding: &PositionEncodingKind,
+    ) -> Vec<Diagnostic> {
+        let position = |lc| position(text, lc, encoding);
+        self.iter()
+            .map(|e| Diagnostic {
+                range: e
+                    .span()
+                    .map(|span| Range::new(position(span.start), position(span.end)))
+                    .unwrap_or_default(),
+                severity: Some(match e.severity() {
+                    Severity::Hint => DiagnosticSeverity::HINT,
+                    Severity::Note => DiagnosticSeverity::INFORMATION,
+                    Severity::Warning => DiagnosticSeverity::WARNING,
+                    Severity::Error | Severity::Fatal => DiagnosticSeverity::ERROR,
+                }),
+                code: e.code().map(NumberOrString::String),
+                source: Some(source.to_string()),
+                message: e.message().trim_end().to_string(),
+                ..Diagnostic::default()
+            })
+            .collect()
+    }
+}
+
+/// The 0-based [Position] of `lc` within `text`, counting characters in `encoding`.
+///
+/// Columns past the end of their line, or lines past the end of `text`, count the excess as one
+/// unit per `char`.
+fn position(text: &str, lc: LineCol, encoding: &PositionEncodingKind) -> Position {
+    let to_u32 = |n: usize| u32::try_from(n).unwrap_or(u32::MAX);
+    let chars = lc.column.saturating_sub(1);
+    let line = text
+        .split('\n')
+        .nth(lc.line.saturating_sub(1))
+        .unwrap_or("");
+    let prefix: &str = match line.char_indices().nth(chars) {
+        Some((i, _)) => &line[..i],
+        None => line,
+    };
+    let excess = chars - prefix.chars().count();
+    let units = if *encoding == PositionEncodingKind::UTF8 {
+        prefix.len()
+    } else if *encoding == PositionEncodingKind::UTF32 {
+        prefix.chars().count()
+    } else {
+        prefix.encode_utf16().count()
+    };
+    Position::new(to_u32(lc.line.saturating_sub(1)), to_u32(units + excess))
+}
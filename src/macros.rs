@@ -0,0 +1,99 @@
+/// Record an error into an [ErrorVec](crate::ErrorVec) unless a condition holds, evaluating to
+/// whether the condition held.
+///
+/// The error is either a format string with arguments, which requires the error type to implement
+/// `From<String>`, or any expression convertible into the error type with [From]. Because the
+/// macro evaluates to a `bool`, dependent checks can be skipped with `continue` or `return`:
+///
+/// ```
+/// use errorvec::{ensure_all, ErrorVec};
+///
+/// let mut errs: ErrorVec<String> = ErrorVec::default();
+/// for (name, age) in [("alice", 30), ("", 200), ("bob", -1)] {
+///     if !ensure_all!(errs, !name.is_empty(), "record with age {age} has no name") {
+///         continue;
+///     }
+///     ensure_all!(errs, age >= 0, "{}'s age is negative", name);
+/// }
+///
+/// assert_eq!(
+///     vec!["record with age 200 has no name", "bob's age is negative"],
+///     errs.as_slice(),
+/// );
+/// ```
+#[macro_export]
+macro_rules! ensure_all {
+    ($ev:expr, $cond:expr, $msg:literal $(, $arg:expr)* $(,)?) => {
+        $crate::ensure_all!($ev, $cond, ::std::format!($msg $(, $arg)*))
+    };
+    ($ev:expr, $cond:expr, $err:expr $(,)?) => {{
+        let ok: bool = $cond;
+        if !ok {
+            $ev.push(::std::convert::From::from($err));
+        }
+        ok
+    }};
+}
+
+/// Unconditionally record an error into an [ErrorVec](crate::ErrorVec).
+///
+/// The error is either a format string with arguments, which requires the error type to implement
+/// `From<String>`, or any expression convertible into the error type with [From]:
+///
+/// ```
+/// use errorvec::{fail, ErrorVec};
+///
+/// let mut errs: ErrorVec<Box<dyn std::error::Error>> = ErrorVec::default();
+/// for input in ["1", "two", "3"] {
+///     match input.parse::<u32>() {
+///         Ok(_) => {}
+///         Err(e) if input.len() > 2 => fail!(errs, "{input:?} is not a digit: {e}"),
+///         Err(e) => fail!(errs, e),
+///     }
+/// }
+///
+/// assert_eq!(1, errs.len());
+/// assert_eq!(
+///     r#""two" is not a digit: invalid digit found in string"#,
+///     errs[0].to_string(),
+/// );
+/// ```
+#[macro_export]
+macro_rules! fail {
+    ($ev:expr, $msg:literal $(, $arg:expr)* $(,)?) => {
+        $crate::fail!($ev, ::std::format!($msg $(, $arg)*))
+    };
+    ($ev:expr, $err:expr $(,)?) => {
+        $ev.push(::std::convert::From::from($err))
+    };
+}
+
+/// Assert that an [ErrorVec](crate::ErrorVec) is empty, panicking with the full report otherwise.
+///
+/// An optional format string and arguments describe the assertion:
+///
+/// ```
+/// use errorvec::{assert_no_errors, ErrorVec};
+///
+/// let ev: ErrorVec<&str> = ErrorVec::default();
+/// assert_no_errors!(ev);
+/// assert_no_errors!(ev, "while loading {}", "config.toml");
+/// ```
+///
+/// ```should_panic
+/// use errorvec::{assert_no_errors, ErrorVec};
+///
+/// let ev: ErrorVec<&str> = ["whoops", "ouch!"].into_iter().collect();
+/// assert_no_errors!(ev);
+/// ```
+#[macro_export]
+macro_rules! assert_no_errors {
+    ($ev:expr $(,)?) => {
+        $crate::ErrorVec::expect_empty(&$ev, ::std::concat!(
+            "assertion failed: `", ::std::stringify!($ev), "` has no errors"
+        ))
+    };
+    ($ev:expr, $($arg:tt)+) => {
+        $crate::ErrorVec::expect_empty(&$ev, &::std::format!($($arg)+))
+    };
+}
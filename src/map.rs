@@ -0,0 +1,76 @@
+use crate::ReportEntry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+/// A map which [ResultIterator::try_collect_map](crate::ResultIterator::try_collect_map) can
+/// build from `entry` items, rejecting duplicate keys.
+pub trait UniqueKeyMap<Entry>: Default {
+    /// The key type.
+    type Key;
+    /// The value type.
+    type Value;
+
+    /// Insert `entry` unless its key is already present, otherwise return its key and value.
+    fn insert_unique(&mut self, entry: Entry) -> Result<(), (Self::Key, Self::Value)>;
+}
+
+impl<K, V, S> UniqueKeyMap<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Key = K;
+    type Value = V;
+
+    // The entry API would consume the key, which a duplicate must return.
+    #[allow(clippy::map_entry)]
+    fn insert_unique(&mut self, (key, value): (K, V)) -> Result<(), (K, V)> {
+        if self.contains_key(&key) {
+            Err((key, value))
+        } else {
+            self.insert(key, value);
+            Ok(())
+        }
+    }
+}
+
+impl<K, V> UniqueKeyMap<(K, V)> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Key = K;
+    type Value = V;
+
+    // The entry API would consume the key, which a duplicate must return.
+    #[allow(clippy::map_entry)]
+    fn insert_unique(&mut self, (key, value): (K, V)) -> Result<(), (K, V)> {
+        if self.contains_key(&key) {
+            Err((key, value))
+        } else {
+            self.insert(key, value);
+            Ok(())
+        }
+    }
+}
+
+/// A key which occurred more than once, gathered by
+/// [ResultIterator::try_collect_map](crate::ResultIterator::try_collect_map).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateKey<K> {
+    /// The repeated key.
+    pub key: K,
+}
+
+impl<K> fmt::Display for DuplicateKey<K>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "duplicate key {:?}", self.key)
+    }
+}
+
+impl<K> std::error::Error for DuplicateKey<K> where K: fmt::Debug {}
+
+impl<K> ReportEntry for DuplicateKey<K> where K: fmt::Debug {}
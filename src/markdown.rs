@@ -0,0 +1,102 @@
+use crate::errorvec::count_errors;
+use crate::{ErrorVec, ReportEntry};
+use std::fmt::Write;
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Render the report as Markdown suitable for a pull request comment: a bold count, then a
+    /// numbered list with each entry's [ReportEntry::severity] and text.
+    ///
+    /// Each entry's [ReportEntry::source_chain] and [ReportEntry::children] are listed beneath it
+    /// in collapsible `<details>` sections, with nested entries labeled by key or count as in
+    /// [ErrorVec::display_tree]. Text inside backtick code spans is kept verbatim, and other
+    /// Markdown syntax is escaped:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let stage: ErrorVec<&str> = ["unknown type `Foo_Bar`"].into_iter().collect();
+    /// let ev: ErrorVec<ErrorVec<&str>> = [stage].into_iter().collect();
+    ///
+    /// let expected = r#"
+    /// **1 error**
+    ///
+    /// 1. **error**: 1 error
+    ///    <details><summary>entries</summary>
+    ///
+    ///    1. **error**: unknown type `Foo_Bar`
+    ///
+    ///    </details>
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected, ev.to_markdown());
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("**{}**\n\n", count_errors(self.len()));
+        if self.is_empty() {
+            md.push_str("No errors.\n");
+        }
+        for (i, e) in self.iter().enumerate() {
+            write_item(&mut md, "", i + 1, e);
+        }
+        md
+    }
+}
+
+fn write_item(md: &mut String, indent: &str, number: usize, e: &dyn ReportEntry) {
+    let children = e.children();
+    let text = if children.is_empty() {
+        e.to_string()
+    } else {
+        e.key().unwrap_or_else(|| count_errors(children.len()))
+    };
+    let mut lines = text.trim_end().lines();
+    let first = lines.next().unwrap_or("");
+    let _ = writeln!(
+        md,
+        "{}{}. **{}**: {}",
+        indent,
+        number,
+        e.severity(),
+        markdown_escape(first)
+    );
+
+    let inner = format!("{}   ", indent);
+    for line in lines {
+        let _ = writeln!(md, "{}{}", inner, markdown_escape(line));
+    }
+
+    let sources = e.source_chain();
+    if !sources.is_empty() {
+        let _ = writeln!(md, "{}<details><summary>caused by</summary>\n", inner);
+        for source in sources {
+            let _ = writeln!(md, "{}- {}", inner, markdown_escape(&source));
+        }
+        let _ = writeln!(md, "\n{}</details>", inner);
+    }
+
+    if !children.is_empty() {
+        let _ = writeln!(md, "{}<details><summary>entries</summary>\n", inner);
+        for (i, child) in children.into_iter().enumerate() {
+            write_item(md, &inner, i + 1, child);
+        }
+        let _ = writeln!(md, "\n{}</details>", inner);
+    }
+}
+
+/// Escape Markdown syntax in `s`, leaving backtick code spans untouched.
+fn markdown_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    let mut in_code = false;
+    for c in s.chars() {
+        match c {
+            '`' => in_code = !in_code,
+            '\\' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' if !in_code => escaped.push('\\'),
+            _ => {}
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,61 @@
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+
+/// A message-only error, for tools which only need to record formatted text.
+///
+/// Unlike [String], it implements [std::error::Error], so it can be boxed or wrapped by other
+/// error types. The [fail!](crate::fail) macro also records formatted messages:
+///
+/// ```
+/// use errorvec::{fail, ErrorVec, StringError};
+///
+/// let mut errs: ErrorVec<StringError> = ErrorVec::default();
+/// for (line, text) in ["ok", "", "ok"].into_iter().enumerate() {
+///     if text.is_empty() {
+///         errs.push_msg(format_args!("line {}: empty", line + 1));
+///     }
+/// }
+/// fail!(errs, "{} lines checked", 3);
+///
+/// let expected_display = r#"
+/// [error 1 of 2] line 2: empty
+///
+/// [error 2 of 2] 3 lines checked
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StringError(pub String);
+
+impl fmt::Display for StringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for StringError {}
+
+impl ReportEntry for StringError {}
+
+impl From<String> for StringError {
+    fn from(s: String) -> Self {
+        StringError(s)
+    }
+}
+
+impl From<&str> for StringError {
+    fn from(s: &str) -> Self {
+        StringError(s.to_string())
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: From<String>,
+{
+    /// Record a formatted message, as from [format_args!].
+    pub fn push_msg(&mut self, args: fmt::Arguments) {
+        self.push(E::from(args.to_string()));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::ErrorVec;
+use std::fmt;
+
+impl<E> ErrorVec<E> {
+    /// Increment the counter `name` by the number of errors, and the counter `{name}_by_class`
+    /// once per error, labeled with `class` given by `classify`:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<io::Error> = [io::ErrorKind::NotFound, io::ErrorKind::TimedOut]
+    ///     .into_iter()
+    ///     .map(io::Error::from)
+    ///     .collect();
+    ///
+    /// // Reports to whichever `metrics` recorder is installed.
+    /// ev.record_metrics("ingest_errors", |e| format!("{:?}", e.kind()));
+    /// ```
+    pub fn record_metrics<K, F>(&self, name: &str, classify: F)
+    where
+        K: fmt::Display,
+        F: Fn(&E) -> K,
+    {
+        metrics::counter!(name.to_string()).increment(self.len() as u64);
+        let by_class = format!("{}_by_class", name);
+        for e in self.iter() {
+            metrics::counter!(by_class.clone(), "class" => classify(e).to_string()).increment(1);
+        }
+    }
+}
+
+/// An iterator adapter which increments a counter for each `Err` passing through, produced by
+/// [ResultIterator::record_error_metrics](crate::ResultIterator::record_error_metrics).
+#[derive(Debug)]
+pub struct RecordErrorMetrics<I> {
+    iter: I,
+    counter: metrics::Counter,
+}
+
+impl<I> RecordErrorMetrics<I> {
+    pub(crate) fn new(iter: I, name: &str) -> Self {
+        RecordErrorMetrics {
+            iter,
+            counter: metrics::counter!(name.to_string()),
+        }
+    }
+}
+
+impl<I, O, E> Iterator for RecordErrorMetrics<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if item.is_err() {
+            self.counter.increment(1);
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::ErrorVec;
+use std::error::Error;
+
+/// A collection of errors, for code which accepts any of this crate's aggregates without
+/// committing to one:
+///
+/// ```
+/// use errorvec::{ErrorVec, GroupedErrorVec, MultiError};
+///
+/// fn log_all<M>(errs: &M) -> Vec<String>
+/// where
+///     M: MultiError,
+///     M::Error: std::fmt::Display,
+/// {
+///     errs.errors().map(|e| e.to_string()).collect()
+/// }
+///
+/// let ev: ErrorVec<&str> = ["disk full"].into_iter().collect();
+/// let mut grouped = GroupedErrorVec::default();
+/// grouped.push("b.txt", "not found");
+/// grouped.push("a.txt", "denied");
+///
+/// assert_eq!(vec!["disk full"], log_all(&ev));
+/// assert_eq!(vec!["denied", "not found"], log_all(&grouped));
+/// ```
+pub trait MultiError: Sized {
+    /// The type of each error.
+    type Error;
+
+    /// The number of errors.
+    fn error_count(&self) -> usize;
+
+    /// Iterate over the errors.
+    fn errors(&self) -> impl Iterator<Item = &Self::Error>;
+
+    /// Add the errors of `other` to `self`.
+    fn merge(&mut self, other: Self);
+
+    /// Convert into a plain [ErrorVec], discarding any extra structure.
+    fn into_errors(self) -> ErrorVec<Self::Error>;
+
+    /// Convert into an [ErrorVec] of boxed errors, erasing the error type:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::{ErrorVec, MultiError};
+    ///
+    /// let ev: ErrorVec<io::Error> = vec![io::ErrorKind::NotFound.into()].into();
+    /// let boxed: ErrorVec<Box<dyn std::error::Error + Send + Sync>> = ev.into_boxed();
+    /// assert_eq!(1, boxed.len());
+    /// ```
+    fn into_boxed(self) -> ErrorVec<Box<dyn Error + Send + Sync>>
+    where
+        Self::Error: Error + Send + Sync + 'static,
+    {
+        self.into_errors()
+            .into_iter()
+            .map(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+            .collect()
+    }
+}
+
+impl<E> MultiError for ErrorVec<E> {
+    type Error = E;
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &E> {
+        self.iter()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.extend(other);
+    }
+
+    fn into_errors(self) -> ErrorVec<E> {
+        self
+    }
+}
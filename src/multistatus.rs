@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A per-item batch outcome body, suitable for an HTTP `207 Multi-Status` response.
+///
+/// ```
+/// use errorvec::MultiStatus;
+///
+/// let outcomes = vec![
+///     ("a", Ok(())),
+///     ("b", Err("duplicate id")),
+///     ("c", Ok(())),
+/// ];
+/// let ms = MultiStatus::from_results(outcomes);
+///
+/// assert_eq!(1, ms.failed);
+/// assert_eq!(
+///     serde_json::to_value(&ms).unwrap(),
+///     serde_json::json!({
+///         "succeeded": 2,
+///         "failed": 1,
+///         "items": [
+///             { "key": "a", "status": 200 },
+///             { "key": "b", "status": 400, "message": "duplicate id" },
+///             { "key": "c", "status": 200 },
+///         ],
+///     }),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MultiStatus {
+    /// The number of successful items.
+    pub succeeded: usize,
+    /// The number of failed items.
+    pub failed: usize,
+    /// The outcome of each item, in order.
+    pub items: Vec<MultiStatusItem>,
+}
+
+/// The outcome of one item within a [MultiStatus].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MultiStatusItem {
+    /// The display text of the item key.
+    pub key: String,
+    /// The HTTP status code of this item.
+    pub status: u16,
+    /// The error display text for a failed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl MultiStatus {
+    /// The HTTP status code for a whole [MultiStatus] response.
+    pub const STATUS: u16 = 207;
+
+    /// Build a [MultiStatus] where successes have status `200` and failures have status `400`.
+    pub fn from_results<I, K, O, E>(results: I) -> Self
+    where
+        I: IntoIterator<Item = (K, Result<O, E>)>,
+        K: fmt::Display,
+        E: fmt::Display,
+    {
+        MultiStatus::from_results_with(results, |_| 400)
+    }
+
+    /// Build a [MultiStatus] where successes have status `200` and each failure has the status
+    /// given by `error_status`.
+    pub fn from_results_with<I, K, O, E, F>(results: I, error_status: F) -> Self
+    where
+        I: IntoIterator<Item = (K, Result<O, E>)>,
+        K: fmt::Display,
+        E: fmt::Display,
+        F: Fn(&E) -> u16,
+    {
+        let mut ms = MultiStatus {
+            succeeded: 0,
+            failed: 0,
+            items: vec![],
+        };
+
+        for (key, result) in results {
+            let key = key.to_string();
+            let item = match result {
+                Ok(_) => {
+                    ms.succeeded += 1;
+                    MultiStatusItem {
+                        key,
+                        status: 200,
+                        message: None,
+                    }
+                }
+                Err(e) => {
+                    ms.failed += 1;
+                    MultiStatusItem {
+                        key,
+                        status: error_status(&e),
+                        message: Some(e.to_string().trim_end().to_string()),
+                    }
+                }
+            };
+            ms.items.push(item);
+        }
+
+        ms
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{ErrorVec, ResultIterator};
+
+impl<E> ErrorVec<E> {
+    /// Gather results tagged with their original input index, such as those arriving from worker
+    /// threads in completion order, returning the `Ok` values and errors in input order:
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// std::thread::scope(|s| {
+    ///     for (i, input) in ["1", "x", "3", "y"].into_iter().enumerate() {
+    ///         let tx = tx.clone();
+    ///         s.spawn(move || tx.send((i, input.parse::<u32>().map_err(|_| input))).unwrap());
+    ///     }
+    /// });
+    /// drop(tx);
+    ///
+    /// let ev = ErrorVec::gather_ordered(rx).unwrap_err();
+    /// assert_eq!(&["x", "y"], ev.as_slice());
+    /// ```
+    pub fn gather_ordered<I, O>(items: I) -> Result<Vec<O>, ErrorVec<E>>
+    where
+        I: IntoIterator<Item = (usize, Result<O, E>)>,
+    {
+        let mut items: Vec<_> = items.into_iter().collect();
+        items.sort_by_key(|(i, _)| *i);
+        items.into_iter().map(|(_, r)| r).into_errorvec_result()
+    }
+}
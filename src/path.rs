@@ -0,0 +1,124 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, GroupedErrorVec, ReportEntry};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// An error paired with the filesystem path which caused it.
+///
+/// `Display` prefixes the error with the path, and [ReportEntry::key] is the path:
+///
+/// ```
+/// use std::io;
+/// use errorvec::ErrorVec;
+///
+/// let mut errs = ErrorVec::default();
+/// for path in ["b.txt", "a.txt"] {
+///     errs.take_error_at(path, Err::<(), _>(io::Error::from(io::ErrorKind::NotFound)));
+/// }
+/// errs.sort_by_path();
+///
+/// let expected_display = r#"
+/// [error 1 of 2] a.txt: entity not found
+///
+/// [error 2 of 2] b.txt: entity not found
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathError<E> {
+    /// The path being operated on.
+    pub path: PathBuf,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> PathError<E> {
+    /// Pair `error` with `path`.
+    pub fn new<P>(path: P, error: E) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        PathError {
+            path: path.into(),
+            error,
+        }
+    }
+
+    /// Discard the path.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for PathError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.error)
+    }
+}
+
+impl<E> std::error::Error for PathError<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for PathError<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, source_chain, metadata, span, severity, children, code);
+
+    fn key(&self) -> Option<String> {
+        Some(self.path.display().to_string())
+    }
+}
+
+impl<E> ErrorVec<PathError<E>> {
+    /// Like [ErrorVec::take_error], attaching `path` to the error if present.
+    pub fn take_error_at<P, T>(&mut self, path: P, r: Result<T, E>) -> Option<T>
+    where
+        P: AsRef<Path>,
+    {
+        self.take_error(r.map_err(|error| PathError::new(path.as_ref(), error)))
+    }
+
+    /// Stably sort the errors by path.
+    pub fn sort_by_path(&mut self) {
+        self.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+
+    /// Group the errors by path, preserving order within each path:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, PathError};
+    /// use std::path::Path;
+    ///
+    /// let ev: ErrorVec<_> = [
+    ///     PathError::new("a.toml", "missing `name`"),
+    ///     PathError::new("b.toml", "bad port"),
+    ///     PathError::new("a.toml", "unknown key `nmae`"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let grouped = ev.group_by_path();
+    /// assert_eq!(
+    ///     &["missing `name`", "unknown key `nmae`"],
+    ///     grouped.group(&Path::new("a.toml").to_path_buf()).unwrap().as_slice(),
+    /// );
+    /// ```
+    pub fn group_by_path(self) -> GroupedErrorVec<PathBuf, E> {
+        let mut grouped = GroupedErrorVec::default();
+        for e in self {
+            grouped.push(e.path, e.error);
+        }
+        grouped
+    }
+}
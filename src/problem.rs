@@ -0,0 +1,133 @@
+use crate::errorvec::count_errors;
+use crate::{ErrorVec, JsonEntry, ReportEntry};
+use serde::{Deserialize, Serialize};
+
+/// An [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) "problem details" body carrying every
+/// error of an [ErrorVec] in an `errors` extension member.
+///
+/// The defaults describe a generic `400 Bad Request`; use the `with_*` methods to customize:
+///
+/// ```
+/// use errorvec::ErrorVec;
+///
+/// let ev: ErrorVec<&str> = ["name is required", "age must be positive"].into_iter().collect();
+/// let problem = ev
+///     .to_problem_details()
+///     .with_type("https://example.com/probs/validation")
+///     .with_title("Your request parameters didn't validate.")
+///     .with_status(422);
+///
+/// assert_eq!(
+///     serde_json::to_value(&problem).unwrap(),
+///     serde_json::json!({
+///         "type": "https://example.com/probs/validation",
+///         "title": "Your request parameters didn't validate.",
+///         "status": 422,
+///         "detail": "2 errors occurred",
+///         "errors": [
+///             { "index": 1, "message": "name is required" },
+///             { "index": 2, "message": "age must be positive" },
+///         ],
+///     }),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ProblemDetails {
+    /// A URI reference identifying the problem type.
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    /// A short, human-readable summary of the problem type.
+    pub title: String,
+    /// The HTTP status code.
+    pub status: u16,
+    /// A human-readable explanation specific to this occurrence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// A URI reference identifying this specific occurrence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Every gathered error.
+    pub errors: Vec<JsonEntry>,
+}
+
+impl ProblemDetails {
+    /// The media type for serialized [ProblemDetails].
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Set the problem `type` URI.
+    pub fn with_type<S>(self, type_uri: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ProblemDetails {
+            type_uri: type_uri.into(),
+            ..self
+        }
+    }
+
+    /// Set the `title`.
+    pub fn with_title<S>(self, title: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ProblemDetails {
+            title: title.into(),
+            ..self
+        }
+    }
+
+    /// Set the HTTP `status`.
+    pub fn with_status(self, status: u16) -> Self {
+        ProblemDetails { status, ..self }
+    }
+
+    /// Set the `detail`.
+    pub fn with_detail<S>(self, detail: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ProblemDetails {
+            detail: Some(detail.into()),
+            ..self
+        }
+    }
+
+    /// Set the `instance` URI.
+    pub fn with_instance<S>(self, instance: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ProblemDetails {
+            instance: Some(instance.into()),
+            ..self
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Build the [ProblemDetails] for these errors.
+    pub fn to_problem_details(&self) -> ProblemDetails {
+        let report = self.to_json_report();
+        ProblemDetails {
+            type_uri: "about:blank".to_string(),
+            title: "Bad Request".to_string(),
+            status: 400,
+            detail: Some(format!("{} occurred", count_errors(report.count))),
+            instance: None,
+            errors: report.errors,
+        }
+    }
+}
+
+impl<E> From<ErrorVec<E>> for ProblemDetails
+where
+    E: ReportEntry,
+{
+    fn from(ev: ErrorVec<E>) -> Self {
+        ev.to_problem_details()
+    }
+}
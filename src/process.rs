@@ -0,0 +1,185 @@
+//! Subprocess batches which run every command and gather every failure.
+//!
+//! ```
+//! use std::process::Command;
+//! use errorvec::process::{run_all, CommandFailure};
+//!
+//! let mut fails = Command::new("sh");
+//! fails.args(["-c", "echo 'no such target' >&2; exit 3"]);
+//!
+//! let errs = run_all([Command::new("true"), fails, Command::new("errorvec-missing-tool")])
+//!     .unwrap_err();
+//!
+//! assert_eq!(2, errs.len());
+//! assert!(matches!(errs[0].failure, CommandFailure::Exit { .. }));
+//! assert!(matches!(errs[1].failure, CommandFailure::Spawn(_)));
+//! assert!(errs[0].to_string().ends_with("exit status: 3\nno such target"));
+//!
+//! let outputs = run_all([Command::new("echo")]).unwrap();
+//! assert_eq!(b"\n", outputs[0].stdout.as_slice());
+//! ```
+
+use crate::{error_source_chain, ErrorVec, ReportEntry, ResultIterator};
+use std::borrow::BorrowMut;
+use std::fmt;
+use std::io;
+use std::process::{Command, ExitStatus, Output};
+use std::sync::Mutex;
+use std::thread;
+
+/// A command which could not be run, or which exited unsuccessfully.
+///
+/// `Display` prefixes the failure with the command line, and [ReportEntry::key] is the command
+/// line.
+#[derive(Debug)]
+pub struct CommandError {
+    /// The program and its arguments, separated by spaces.
+    pub command: String,
+    /// How the command failed.
+    pub failure: CommandFailure,
+}
+
+/// How a command failed within a [CommandError].
+#[derive(Debug)]
+pub enum CommandFailure {
+    /// The command could not be started.
+    Spawn(io::Error),
+    /// The command exited unsuccessfully.
+    Exit {
+        /// The exit status.
+        status: ExitStatus,
+        /// The captured standard error, decoded lossily.
+        stderr: String,
+    },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.command, self.failure)
+    }
+}
+
+impl fmt::Display for CommandFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandFailure::Spawn(e) => write!(f, "failed to start: {}", e),
+            CommandFailure::Exit { status, stderr } => {
+                write!(f, "{}", status)?;
+                let stderr = stderr.trim_end();
+                if !stderr.is_empty() {
+                    write!(f, "\n{}", stderr)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.failure {
+            CommandFailure::Spawn(e) => e.source(),
+            CommandFailure::Exit { .. } => None,
+        }
+    }
+}
+
+impl ReportEntry for CommandError {
+    fn key(&self) -> Option<String> {
+        Some(self.command.clone())
+    }
+
+    fn source_chain(&self) -> Vec<String> {
+        error_source_chain(self)
+    }
+}
+
+/// Run every command to completion in order, capturing output, and return each [Output] in order,
+/// or every failure.
+pub fn run_all<I, C>(commands: I) -> Result<Vec<Output>, ErrorVec<CommandError>>
+where
+    I: IntoIterator<Item = C>,
+    C: BorrowMut<Command>,
+{
+    commands
+        .into_iter()
+        .map(|mut cmd| run(cmd.borrow_mut()))
+        .into_errorvec_result()
+}
+
+/// As [run_all], running up to `max_parallel` commands at a time. Outputs and failures are
+/// still reported in the order of `commands`:
+///
+/// ```
+/// use std::process::Command;
+/// use errorvec::process::run_all_concurrent;
+///
+/// let commands = (1..=4).map(|i| {
+///     let mut cmd = Command::new("sh");
+///     cmd.args(["-c", &format!("exit $(({i} % 2))")]);
+///     cmd
+/// });
+///
+/// let errs = run_all_concurrent(commands, 2).unwrap_err();
+/// assert_eq!(2, errs.len());
+/// assert_eq!("sh -c exit $((1 % 2))", errs[0].command);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_parallel` is 0.
+pub fn run_all_concurrent<I, C>(
+    commands: I,
+    max_parallel: usize,
+) -> Result<Vec<Output>, ErrorVec<CommandError>>
+where
+    I: IntoIterator<Item = C>,
+    C: BorrowMut<Command> + Send,
+{
+    assert!(max_parallel > 0, "max_parallel must be non-zero");
+    let queue = Mutex::new(
+        commands
+            .into_iter()
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter(),
+    );
+    let results = Mutex::new(vec![]);
+
+    thread::scope(|s| {
+        for _ in 0..max_parallel {
+            s.spawn(|| loop {
+                let next = queue.lock().expect("queue lock poisoned").next();
+                let Some((i, mut cmd)) = next else {
+                    break;
+                };
+                let result = run(cmd.borrow_mut());
+                results
+                    .lock()
+                    .expect("results lock poisoned")
+                    .push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().expect("results lock poisoned");
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).into_errorvec_result()
+}
+
+fn run(cmd: &mut Command) -> Result<Output, CommandError> {
+    let command = std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|s| s.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let failure = match cmd.output() {
+        Ok(output) if output.status.success() => return Ok(output),
+        Ok(output) => CommandFailure::Exit {
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        },
+        Err(e) => CommandFailure::Spawn(e),
+    };
+    Err(CommandError { command, failure })
+}
@@ -0,0 +1,41 @@
+use crate::ErrorVec;
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+
+/// Generates aggregates whose length is drawn from the [SizeRange] parameter, which defaults to
+/// `0..100` and so covers the empty and single-error shapes:
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+/// use errorvec::ErrorVec;
+///
+/// TestRunner::default()
+///     .run(&any::<ErrorVec<u8>>(), |ev| {
+///         let len = ev.len();
+///         prop_assert_eq!(len == 0, ev.into_result().is_ok());
+///         Ok(())
+///     })
+///     .unwrap();
+///
+/// // Only the tricky shapes:
+/// let strategy = any_with::<ErrorVec<String>>(((0..=1).into(), Default::default()));
+/// TestRunner::default()
+///     .run(&strategy, |ev| {
+///         prop_assert!(ev.len() <= 1);
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+impl<E> Arbitrary for ErrorVec<E>
+where
+    E: Arbitrary,
+{
+    type Parameters = (SizeRange, E::Parameters);
+    type Strategy = Map<VecStrategy<E::Strategy>, fn(Vec<E>) -> Self>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        vec(any_with::<E>(params), size).prop_map(ErrorVec::from)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::ErrorVec;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+/// Extend [rayon::iter::ParallelIterator] with `Item = Result<T, E>` to support gathering multiple
+/// errors in parallel, like [ResultIterator](crate::ResultIterator) does sequentially.
+pub trait ParallelResultIterator<O, E>: ParallelIterator<Item = Result<O, E>>
+where
+    O: Send,
+    E: Send,
+{
+    /// Gather all `Ok` and `Err` values, returning `Err` if there are 1 or more errors.
+    ///
+    /// The order of values is only deterministic for indexed parallel iterators; use
+    /// [ParallelResultIterator::into_errorvec_result_ordered] to make that explicit, or tag items
+    /// with their input index and use [ErrorVec::gather_ordered] for other sources.
+    fn into_errorvec_result(self) -> Result<Vec<O>, ErrorVec<E>> {
+        let (oks, ev) = self
+            .fold(
+                || (vec![], ErrorVec::default()),
+                |(mut oks, mut ev), result| {
+                    if let Some(v) = ev.take_error(result) {
+                        oks.push(v);
+                    }
+                    (oks, ev)
+                },
+            )
+            .reduce(
+                || (vec![], ErrorVec::default()),
+                |(mut oks, mut ev), (more_oks, more_ev)| {
+                    oks.extend(more_oks);
+                    ev.extend(more_ev);
+                    (oks, ev)
+                },
+            );
+        ev.into_result_with(oks)
+    }
+
+    /// Gather all `Ok` and `Err` values in input order, regardless of completion order, returning
+    /// `Err` if there are 1 or more errors:
+    ///
+    /// ```
+    /// use errorvec::ParallelResultIterator;
+    /// use rayon::prelude::*;
+    ///
+    /// let inputs: Vec<String> = (0..1000).map(|n| n.to_string()).chain(["x".into(), "y".into()]).collect();
+    /// let ev = inputs
+    ///     .par_iter()
+    ///     .map(|s| s.parse::<u32>().map_err(|_| s.as_str()))
+    ///     .into_errorvec_result_ordered()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(&["x", "y"], ev.as_slice());
+    /// ```
+    fn into_errorvec_result_ordered(self) -> Result<Vec<O>, ErrorVec<E>>
+    where
+        Self: IndexedParallelIterator,
+    {
+        ErrorVec::gather_ordered(self.enumerate().collect::<Vec<_>>())
+    }
+}
+
+impl<T, O, E> ParallelResultIterator<O, E> for T
+where
+    T: ParallelIterator<Item = Result<O, E>>,
+    O: Send,
+    E: Send,
+{
+}
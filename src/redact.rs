@@ -0,0 +1,149 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::cell::OnceCell;
+use std::fmt;
+
+/// An entry whose rendered text is passed through a redaction function, produced by
+/// [ErrorVec::redacted].
+///
+/// Redaction applies to the `Display` text and to the [ReportEntry] message, key, code, source
+/// chain, and metadata values, so every output format built on them masks the same substrings.
+/// The [ReportEntry::children] of nested entries are themselves redacted:
+///
+/// ```
+/// use errorvec::{ErrorVec, ReportEntry};
+///
+/// let inner: ErrorVec<&str> = ["login as alice failed"].into_iter().collect();
+/// let outer: ErrorVec<ErrorVec<&str>> = [inner].into_iter().collect();
+/// let mask = |s: &str| s.replace("alice", "****");
+///
+/// let redacted = outer.redacted(&mask);
+/// let children = redacted[0].children();
+/// assert_eq!("login as **** failed", children[0].to_string());
+/// assert_eq!("login as **** failed", children[0].message());
+/// ```
+pub struct Redacted<'a, E, F>
+where
+    E: ?Sized,
+{
+    error: &'a E,
+    redact: &'a F,
+    children: OnceCell<Vec<Redacted<'a, dyn ReportEntry + 'a, F>>>,
+}
+
+impl<E> ErrorVec<E> {
+    /// Borrow the errors with `redact` applied to all of their rendered text:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["GET https://api.test/?token=s3cr3t failed"].into_iter().collect();
+    /// let mask = |s: &str| s.replace("s3cr3t", "****");
+    ///
+    /// assert_eq!(
+    ///     "[error 1 of 1] GET https://api.test/?token=**** failed\n",
+    ///     ev.redacted(&mask).to_string(),
+    /// );
+    /// ```
+    pub fn redacted<'a, F>(&'a self, redact: &'a F) -> ErrorVec<Redacted<'a, E, F>>
+    where
+        F: Fn(&str) -> String,
+    {
+        self.iter()
+            .map(|error| Redacted::new(error, redact))
+            .collect()
+    }
+}
+
+impl<'a, E, F> Redacted<'a, E, F>
+where
+    E: ?Sized,
+{
+    fn new(error: &'a E, redact: &'a F) -> Self {
+        Redacted {
+            error,
+            redact,
+            children: OnceCell::new(),
+        }
+    }
+}
+
+impl<E, F> fmt::Debug for Redacted<'_, E, F>
+where
+    E: fmt::Display + ?Sized,
+    F: Fn(&str) -> String,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Redacted").field(&self.to_string()).finish()
+    }
+}
+
+impl<E, F> fmt::Display for Redacted<'_, E, F>
+where
+    E: fmt::Display + ?Sized,
+    F: Fn(&str) -> String,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&(self.redact)(&self.error.to_string()))
+    }
+}
+
+impl<E, F> std::error::Error for Redacted<'_, E, F>
+where
+    E: fmt::Display + ?Sized,
+    F: Fn(&str) -> String,
+{
+}
+
+impl<E, F> ReportEntry for Redacted<'_, E, F>
+where
+    E: ReportEntry + ?Sized,
+    F: Fn(&str) -> String,
+{
+    forward_report_entry!(error; span, severity);
+
+    fn children(&self) -> Vec<&dyn ReportEntry> {
+        self.children
+            .get_or_init(|| {
+                self.error
+                    .children()
+                    .into_iter()
+                    .map(|child| Redacted::new(child, self.redact))
+                    .collect()
+            })
+            .iter()
+            .map(|child| child as &dyn ReportEntry)
+            .collect()
+    }
+
+    fn message(&self) -> String {
+        (self.redact)(&self.error.message())
+    }
+
+    fn key(&self) -> Option<String> {
+        self.error.key().map(|k| (self.redact)(&k))
+    }
+
+    fn source_chain(&self) -> Vec<String> {
+        self.error
+            .source_chain()
+            .iter()
+            .map(|s| (self.redact)(s))
+            .collect()
+    }
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        self.error
+            .metadata()
+            .into_iter()
+            .map(|(k, v)| {
+                let v = (self.redact)(&v);
+                (k, v)
+            })
+            .collect()
+    }
+
+    fn code(&self) -> Option<String> {
+        self.error.code().map(|c| (self.redact)(&c))
+    }
+}
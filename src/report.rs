@@ -0,0 +1,52 @@
+use crate::ErrorVec;
+use std::fmt;
+use std::io;
+
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Write the `Display` report to `w` one entry at a time, without rendering the whole report
+    /// into memory first:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops", "ouch!"].into_iter().collect();
+    /// let mut out = vec![];
+    /// ev.write_report(&mut out).unwrap();
+    /// assert_eq!(ev.to_string().as_bytes(), out.as_slice());
+    /// ```
+    pub fn write_report<W>(&self, mut w: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        for chunk in self.report_chunks() {
+            w.write_all(chunk.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Like [ErrorVec::write_report], for an asynchronous writer:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["whoops", "ouch!"].into_iter().collect();
+    /// let mut out = vec![];
+    /// futures::executor::block_on(ev.write_report_async(&mut out)).unwrap();
+    /// assert_eq!(ev.to_string().as_bytes(), out.as_slice());
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn write_report_async<W>(&self, mut w: W) -> io::Result<()>
+    where
+        W: futures::io::AsyncWrite + Unpin,
+    {
+        use futures::io::AsyncWriteExt;
+
+        for chunk in self.report_chunks() {
+            w.write_all(chunk.as_bytes()).await?;
+        }
+        w.flush().await
+    }
+}
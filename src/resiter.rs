@@ -1,4 +1,9 @@
-use crate::ErrorVec;
+#[cfg(feature = "metrics")]
+use crate::RecordErrorMetrics;
+use crate::{
+    CancelSignal, ChunkPolicy, ChunksGathering, DuplicateKey, ErrorVec, FilterMapGathering,
+    PartialOutcome, TruncatedErrorVec, UniqueKeyMap,
+};
 
 /// Extend [Iterator] with `Item = Result<T, E>` to support gathering multiple errors.
 ///
@@ -41,8 +46,11 @@ pub trait ResultIterator<O, E>: Sized + Iterator<Item = Result<O, E>> {
     }
 
     /// Gather all `Ok` and `Err` values, returning each.
+    ///
+    /// Space for the `Ok` values is reserved up front from the lower bound of
+    /// [Iterator::size_hint], as in every gathering method which collects them.
     fn into_oks_and_errs(self) -> (Vec<O>, ErrorVec<E>) {
-        let mut oks = vec![];
+        let mut oks = Vec::with_capacity(self.size_hint().0);
         let mut ev = ErrorVec::default();
 
         for result in self {
@@ -53,6 +61,415 @@ pub trait ResultIterator<O, E>: Sized + Iterator<Item = Result<O, E>> {
 
         (oks, ev)
     }
+
+    /// Like [ResultIterator::into_errorvec_result], calling `progress(processed, ok_count,
+    /// err_count)` after each item is consumed:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let mut updates = vec![];
+    /// let res = ["1", "x", "3"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .into_errorvec_result_with_progress(|processed, oks, errs| {
+    ///         updates.push((processed, oks, errs))
+    ///     });
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(vec![(1, 1, 0), (2, 1, 1), (3, 2, 1)], updates);
+    /// ```
+    fn into_errorvec_result_with_progress<F>(self, mut progress: F) -> Result<Vec<O>, ErrorVec<E>>
+    where
+        F: FnMut(usize, usize, usize),
+    {
+        let mut oks = Vec::with_capacity(self.size_hint().0);
+        let mut ev = ErrorVec::default();
+
+        for (i, result) in self.enumerate() {
+            if let Some(v) = ev.take_error(result) {
+                oks.push(v);
+            }
+            progress(i + 1, oks.len(), ev.len());
+        }
+
+        ev.into_result_with(oks)
+    }
+
+    /// Fold every `Ok` value into an accumulator starting from `init`, without buffering them,
+    /// returning the accumulator, or every error if there are 1 or more:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let sum = ["1", "2", "3"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .fold_gathering(0, |acc, n| acc + n);
+    /// assert_eq!(6, sum.unwrap());
+    ///
+    /// let sum = ["1", "x", "y"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .fold_gathering(0, |acc, n| acc + n);
+    /// assert_eq!(2, sum.unwrap_err().len());
+    /// ```
+    fn fold_gathering<B, F>(self, init: B, mut f: F) -> Result<B, ErrorVec<E>>
+    where
+        F: FnMut(B, O) -> B,
+    {
+        let mut acc = init;
+        let mut ev = ErrorVec::default();
+
+        for result in self {
+            if let Some(v) = ev.take_error(result) {
+                acc = f(acc, v);
+            }
+        }
+
+        ev.into_result_with(acc)
+    }
+
+    /// Pass every `Ok` value to `f`, returning the gathered errors:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let mut written = vec![];
+    /// let errs = ["1", "x", "3"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .for_each_gathering(|n| written.push(n));
+    ///
+    /// assert_eq!(vec![1, 3], written);
+    /// assert_eq!(1, errs.len());
+    /// ```
+    fn for_each_gathering<F>(self, mut f: F) -> ErrorVec<E>
+    where
+        F: FnMut(O),
+    {
+        let mut ev = ErrorVec::default();
+
+        for result in self {
+            if let Some(v) = ev.take_error(result) {
+                f(v);
+            }
+        }
+
+        ev
+    }
+
+    /// Lend `f` a streaming iterator over the `Ok` values while gathering every error, returning
+    /// `f`'s result if there were no errors.
+    ///
+    /// Any items `f` leaves unconsumed are drained afterwards so that their errors are gathered
+    /// too:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let max = ["3", "9", "4"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .with_oks(|oks| oks.max());
+    /// assert_eq!(Some(9), max.unwrap());
+    ///
+    /// let first = ["3", "x", "y"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .with_oks(|oks| oks.next());
+    /// assert_eq!(2, first.unwrap_err().len());
+    /// ```
+    fn with_oks<R, F>(mut self, f: F) -> Result<R, ErrorVec<E>>
+    where
+        F: FnOnce(&mut dyn Iterator<Item = O>) -> R,
+    {
+        let mut ev = ErrorVec::default();
+        let mut oks = std::iter::from_fn(|| {
+            for result in self.by_ref() {
+                if let Some(v) = ev.take_error(result) {
+                    return Some(v);
+                }
+            }
+            None
+        });
+        let r = f(&mut oks);
+        oks.for_each(drop);
+        ev.into_result_with(r)
+    }
+
+    /// Like [ResultIterator::into_errorvec_result], but stop consuming the input at the first
+    /// error for which `is_fatal` holds, marking the errors as truncated:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ResultIterator;
+    ///
+    /// let writes: Vec<io::Result<u32>> = vec![
+    ///     Err(io::ErrorKind::PermissionDenied.into()),
+    ///     Ok(1),
+    ///     Err(io::ErrorKind::StorageFull.into()),
+    ///     Ok(2),
+    ///     Err(io::ErrorKind::PermissionDenied.into()),
+    /// ];
+    ///
+    /// let errs = writes
+    ///     .into_iter()
+    ///     .into_errorvec_result_until_fatal(|e| e.kind() == io::ErrorKind::StorageFull)
+    ///     .unwrap_err();
+    ///
+    /// assert!(errs.truncated);
+    /// assert_eq!(2, errs.len());
+    /// assert!(errs.to_string().ends_with("remaining input skipped]\n"));
+    /// ```
+    fn into_errorvec_result_until_fatal<F>(
+        self,
+        is_fatal: F,
+    ) -> Result<Vec<O>, TruncatedErrorVec<E>>
+    where
+        F: Fn(&E) -> bool,
+    {
+        let mut oks = Vec::with_capacity(self.size_hint().0);
+        let mut errors = ErrorVec::default();
+        let mut truncated = false;
+
+        for result in self {
+            match result {
+                Ok(v) => oks.push(v),
+                Err(e) => {
+                    truncated = is_fatal(&e);
+                    errors.push(e);
+                    if truncated {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(oks)
+        } else {
+            Err(TruncatedErrorVec { errors, truncated })
+        }
+    }
+
+    /// Collect `Ok((key, value))` pairs into a map, gathering every `Err` and every repeated key as
+    /// errors, and returning the map only if there were none. The first value for a key is kept:
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use errorvec::{DuplicateKey, ResultIterator};
+    ///
+    /// let pairs = [("a", 1), ("b", 2), ("a", 3)];
+    /// let errs = pairs
+    ///     .into_iter()
+    ///     .map(Ok::<_, DuplicateKey<&str>>)
+    ///     .try_collect_map::<BTreeMap<_, _>>()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!("[error 1 of 1] duplicate key \"a\"\n", errs.to_string());
+    /// ```
+    fn try_collect_map<M>(self) -> Result<M, ErrorVec<E>>
+    where
+        M: UniqueKeyMap<O>,
+        E: From<DuplicateKey<M::Key>>,
+    {
+        self.try_collect_map_with(|key, _| DuplicateKey { key }.into())
+    }
+
+    /// As [ResultIterator::try_collect_map], converting each repeated key and its rejected value
+    /// into an error with `on_duplicate`:
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use errorvec::ResultIterator;
+    ///
+    /// let lines = ["port=80", "host=a", "port=8080", "junk"];
+    /// let errs = lines
+    ///     .into_iter()
+    ///     .map(|l| l.split_once('=').ok_or(format!("no `=` in {l:?}")))
+    ///     .try_collect_map_with::<HashMap<_, _>, _>(|k, v| format!("{k} set again to {v}"))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(&["port set again to 8080", "no `=` in \"junk\""], errs.as_slice());
+    /// ```
+    fn try_collect_map_with<M, F>(self, mut on_duplicate: F) -> Result<M, ErrorVec<E>>
+    where
+        M: UniqueKeyMap<O>,
+        F: FnMut(M::Key, M::Value) -> E,
+    {
+        let mut map = M::default();
+        let mut ev = ErrorVec::default();
+
+        for result in self {
+            if let Some(entry) = ev.take_error(result) {
+                if let Err((k, v)) = map.insert_unique(entry) {
+                    ev.push(on_duplicate(k, v));
+                }
+            }
+        }
+
+        ev.into_result_with(map)
+    }
+
+    /// Like [ResultIterator::into_errorvec_result], but check `cancel` before consuming each item,
+    /// stopping early once it is set. On errors or cancellation, everything gathered so far is
+    /// returned:
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use errorvec::ResultIterator;
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let outcome = ["1", "x", "3", "4"]
+    ///     .into_iter()
+    ///     .inspect(|s| if *s == "3" { cancel.store(true, Ordering::Relaxed) })
+    ///     .map(str::parse::<u32>)
+    ///     .into_errorvec_result_cancellable(&cancel)
+    ///     .unwrap_err();
+    ///
+    /// assert!(outcome.cancelled);
+    /// assert_eq!(vec![1, 3], outcome.value);
+    /// assert_eq!(1, outcome.errors.len());
+    /// assert!(outcome.to_string().ends_with("[cancelled; remaining input skipped]\n"));
+    /// ```
+    fn into_errorvec_result_cancellable<C>(
+        self,
+        cancel: &C,
+    ) -> Result<Vec<O>, PartialOutcome<Vec<O>, E>>
+    where
+        C: CancelSignal + ?Sized,
+    {
+        let mut oks = Vec::with_capacity(self.size_hint().0);
+        let outcome = self.for_each_gathering_cancellable(cancel, |v| oks.push(v));
+        PartialOutcome {
+            value: oks,
+            errors: outcome.errors,
+            cancelled: outcome.cancelled,
+        }
+        .into_result()
+    }
+
+    /// Like [ResultIterator::for_each_gathering], but check `cancel` before consuming each item,
+    /// stopping early once it is set.
+    fn for_each_gathering_cancellable<C, F>(mut self, cancel: &C, mut f: F) -> PartialOutcome<(), E>
+    where
+        C: CancelSignal + ?Sized,
+        F: FnMut(O),
+    {
+        let mut errors = ErrorVec::default();
+        let mut cancelled = false;
+
+        loop {
+            if cancel.is_cancelled() {
+                cancelled = true;
+                break;
+            }
+            let Some(result) = self.next() else {
+                break;
+            };
+            if let Some(v) = errors.take_error(result) {
+                f(v);
+            }
+        }
+
+        PartialOutcome {
+            value: (),
+            errors,
+            cancelled,
+        }
+    }
+
+    /// Count the `Ok` values, discarding them, and gather the errors:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let (succeeded, errs) = ["1", "x", "3"].into_iter().map(str::parse::<u32>).count_outcomes();
+    ///
+    /// assert_eq!(2, succeeded);
+    /// assert_eq!(1, errs.len());
+    /// ```
+    fn count_outcomes(self) -> (usize, ErrorVec<E>) {
+        let mut count = 0;
+        let ev = self.for_each_gathering(|_| count += 1);
+        (count, ev)
+    }
+
+    /// Increment the `metrics` counter `name` for each `Err` as it passes through, so that errors
+    /// are counted while they are gathered:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// let res = ["1", "x"]
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .record_error_metrics("parse_errors")
+    ///     .into_errorvec_result();
+    ///
+    /// assert!(res.is_err());
+    /// ```
+    #[cfg(feature = "metrics")]
+    fn record_error_metrics(self, name: &str) -> RecordErrorMetrics<Self> {
+        RecordErrorMetrics::new(self, name)
+    }
+
+    /// Apply a skip, keep, or fail step `f` to each `Ok` value, lazily yielding the kept values
+    /// while gathering every error, from the input or from `f`:
+    ///
+    /// ```
+    /// use errorvec::ResultIterator;
+    ///
+    /// // Blank lines are skipped; anything else must parse.
+    /// let step = |line: &str| -> Result<Option<u32>, String> {
+    ///     let line = line.trim();
+    ///     if line.is_empty() {
+    ///         Ok(None)
+    ///     } else {
+    ///         line.parse().map(Some).map_err(|_| format!("bad number {line:?}"))
+    ///     }
+    /// };
+    ///
+    /// let mut nums = ["1", " ", "x", "4"].into_iter().map(Ok).filter_map_gathering(step);
+    /// assert_eq!(Some(1), nums.next());
+    /// assert_eq!(Some(4), nums.next());
+    /// assert_eq!(1, nums.errors().len());
+    ///
+    /// let res = ["1", "", "3"].into_iter().map(Ok).filter_map_gathering(step);
+    /// assert_eq!(vec![1, 3], res.into_errorvec_result().unwrap());
+    /// ```
+    fn filter_map_gathering<U, F>(self, f: F) -> FilterMapGathering<Self, F, E>
+    where
+        F: FnMut(O) -> Result<Option<U>, E>,
+    {
+        FilterMapGathering::new(self, f)
+    }
+
+    /// Group the items into chunks of `size`, yielding each chunk's `Ok` values, or its errors if
+    /// it had any, with `policy` deciding when to give up on a chunk or the whole input:
+    ///
+    /// ```
+    /// use errorvec::{ChunkPolicy, ResultIterator};
+    ///
+    /// let inputs = ["1", "2", "x", "y", "5", "6", "z"];
+    /// let chunks: Vec<_> = inputs
+    ///     .into_iter()
+    ///     .map(str::parse::<u32>)
+    ///     .chunks_gathering(2, ChunkPolicy::abort_run_after(1))
+    ///     .collect();
+    ///
+    /// assert_eq!(2, chunks.len());
+    /// assert_eq!(&vec![1, 2], chunks[0].as_ref().unwrap());
+    /// assert_eq!(2, chunks[1].as_ref().unwrap_err().len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn chunks_gathering(self, size: usize, policy: ChunkPolicy) -> ChunksGathering<Self> {
+        ChunksGathering::new(self, size, policy)
+    }
 }
 
 impl<T, O, E> ResultIterator<O, E> for T where T: Sized + Iterator<Item = Result<O, E>> {}
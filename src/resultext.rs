@@ -0,0 +1,78 @@
+use crate::{Context, ErrorVec};
+
+/// Extend `Result<T, ErrorVec<E>>` with methods for composing further gathered errors.
+///
+/// ```
+/// use errorvec::{ErrorVec, ErrorVecResultExt};
+///
+/// fn check_name(name: &str) -> Result<(), ErrorVec<String>> {
+///     if name.is_empty() {
+///         Err(ErrorVec::from(vec!["name is empty".to_string()]))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// fn check_age(age: i32) -> Result<u32, ErrorVec<String>> {
+///     u32::try_from(age).map_err(|_| ErrorVec::from(vec![format!("invalid age {age}")]))
+/// }
+///
+/// let ev = check_name("")
+///     .merge_errors(check_age(-3))
+///     .context_all("record 7")
+///     .unwrap_err();
+///
+/// let expected_display = r#"
+/// [error 1 of 2] record 7: name is empty
+///
+/// [error 2 of 2] record 7: invalid age -3
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &ev.to_string());
+/// ```
+pub trait ErrorVecResultExt<T, E>: Sized {
+    /// Combine with another result, returning both `Ok` values, or every error of either.
+    fn merge_errors<U>(self, other: Result<U, ErrorVec<E>>) -> Result<(T, U), ErrorVec<E>>;
+
+    /// Add one more error, producing `Err` even if `self` was `Ok`.
+    fn push_error(self, e: E) -> Result<T, ErrorVec<E>>;
+
+    /// Convert each error with `f`.
+    fn map_each_err<E2, F>(self, f: F) -> Result<T, ErrorVec<E2>>
+    where
+        F: FnMut(E) -> E2;
+
+    /// Attach a clone of `context` to each error.
+    fn context_all<C>(self, context: C) -> Result<T, ErrorVec<Context<C, E>>>
+    where
+        C: Clone,
+    {
+        self.map_each_err(|e| Context::new(context.clone(), e))
+    }
+}
+
+impl<T, E> ErrorVecResultExt<T, E> for Result<T, ErrorVec<E>> {
+    fn merge_errors<U>(self, other: Result<U, ErrorVec<E>>) -> Result<(T, U), ErrorVec<E>> {
+        match (self, other) {
+            (Ok(t), Ok(u)) => Ok((t, u)),
+            (Ok(_), Err(ev)) | (Err(ev), Ok(_)) => Err(ev),
+            (Err(mut ev), Err(more)) => {
+                ev.extend(more);
+                Err(ev)
+            }
+        }
+    }
+
+    fn push_error(self, e: E) -> Result<T, ErrorVec<E>> {
+        let mut ev = self.err().unwrap_or_default();
+        ev.push(e);
+        Err(ev)
+    }
+
+    fn map_each_err<E2, F>(self, f: F) -> Result<T, ErrorVec<E2>>
+    where
+        F: FnMut(E) -> E2,
+    {
+        self.map_err(|ev| ev.into_iter().map(f).collect())
+    }
+}
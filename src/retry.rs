@@ -0,0 +1,345 @@
+use crate::entry::forward_report_entry;
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, ReportEntry, ReportStrings};
+use std::fmt;
+use std::time::Duration;
+
+/// Track failed items of a batch, with every attempt's error, so that just those items can be
+/// retried:
+///
+/// ```
+/// use errorvec::RetryQueue;
+///
+/// let mut flaky_calls = 0;
+/// let mut upload = |name: &&str| -> Result<usize, String> {
+///     flaky_calls += 1;
+///     match *name {
+///         "corrupt.bin" => Err("checksum mismatch".to_string()),
+///         "flaky.txt" if flaky_calls < 3 => Err("timeout".to_string()),
+///         _ => Ok(name.len()),
+///     }
+/// };
+///
+/// let mut queue = RetryQueue::default();
+/// let mut uploaded = vec![];
+/// for name in ["a.txt", "flaky.txt", "corrupt.bin"] {
+///     if let Some(n) = queue.record(name, upload(&name)) {
+///         uploaded.push((name, n));
+///     }
+/// }
+/// assert_eq!(vec![&"flaky.txt", &"corrupt.bin"], queue.failed_items().collect::<Vec<_>>());
+///
+/// uploaded.extend(queue.retry(&mut upload));
+/// assert_eq!(vec![("a.txt", 5), ("flaky.txt", 9)], uploaded);
+///
+/// let ev = queue.into_result().unwrap_err();
+/// let expected_display = r#"
+/// [error 1 of 1] corrupt.bin: failed after 2 attempts
+///   attempt 1: checksum mismatch
+///   attempt 2: checksum mismatch
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &ev.to_string());
+/// ```
+#[derive(Debug)]
+pub struct RetryQueue<T, E> {
+    failed: Vec<RetryEntry<T, E>>,
+}
+
+/// A failed item and the error of each attempt, oldest first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryEntry<T, E> {
+    /// The item which failed.
+    pub item: T,
+    /// The error from each attempt.
+    pub attempts: Vec<E>,
+}
+
+impl<T, E> Default for RetryQueue<T, E> {
+    fn default() -> Self {
+        RetryQueue { failed: vec![] }
+    }
+}
+
+impl<T, E> RetryQueue<T, E> {
+    /// Record the outcome of a first attempt at `item`, returning the `Ok` value if present.
+    pub fn record<O>(&mut self, item: T, r: Result<O, E>) -> Option<O> {
+        match r {
+            Ok(x) => Some(x),
+            Err(e) => {
+                self.failed.push(RetryEntry {
+                    item,
+                    attempts: vec![e],
+                });
+                None
+            }
+        }
+    }
+
+    /// The items which have not yet succeeded.
+    pub fn failed_items(&self) -> impl Iterator<Item = &T> {
+        self.failed.iter().map(|entry| &entry.item)
+    }
+
+    /// The number of items which have not yet succeeded.
+    pub fn len(&self) -> usize {
+        self.failed.len()
+    }
+
+    /// True when every recorded item has succeeded.
+    pub fn is_empty(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Attempt every failed item again with `f`, removing and returning the items which now
+    /// succeed along with their `Ok` values, and appending each new error to its item's history.
+    pub fn retry<O, F>(&mut self, mut f: F) -> Vec<(T, O)>
+    where
+        F: FnMut(&T) -> Result<O, E>,
+    {
+        let mut succeeded = vec![];
+        let mut still_failed = vec![];
+        for mut entry in std::mem::take(&mut self.failed) {
+            match f(&entry.item) {
+                Ok(x) => succeeded.push((entry.item, x)),
+                Err(e) => {
+                    entry.attempts.push(e);
+                    still_failed.push(entry);
+                }
+            }
+        }
+        self.failed = still_failed;
+        succeeded
+    }
+
+    /// Convert the remaining failures into an [ErrorVec].
+    pub fn into_errorvec(self) -> ErrorVec<RetryEntry<T, E>> {
+        ErrorVec::from(self.failed)
+    }
+
+    /// `Ok(())` if every item succeeded, otherwise `Err` of the remaining failures.
+    pub fn into_result(self) -> Result<(), ErrorVec<RetryEntry<T, E>>> {
+        self.into_errorvec().into_result()
+    }
+}
+
+impl<T, E> fmt::Display for RetryEntry<T, E>
+where
+    T: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<T, E> RetryEntry<T, E>
+where
+    T: fmt::Display,
+    E: fmt::Display,
+{
+    /// Display the entry with the text from `strings`, including [ReportStrings::failed_after]
+    /// and [ReportStrings::attempt]:
+    ///
+    /// ```
+    /// use errorvec::{ReportStrings, RetryEntry};
+    ///
+    /// struct German;
+    ///
+    /// impl ReportStrings for German {
+    ///     fn attempt(&self, number: usize) -> String {
+    ///         format!("Versuch {}", number)
+    ///     }
+    ///
+    ///     fn failed_after(&self, n: usize) -> String {
+    ///         format!("nach {} Versuchen fehlgeschlagen", n)
+    ///     }
+    /// }
+    ///
+    /// let entry = RetryEntry { item: "a.txt", attempts: vec!["Zeitüberschreitung"; 2] };
+    /// let expected = r#"
+    /// a.txt: nach 2 Versuchen fehlgeschlagen
+    ///   Versuch 1: Zeitüberschreitung
+    ///   Versuch 2: Zeitüberschreitung
+    /// "#.trim();
+    ///
+    /// assert_eq!(expected, entry.display_localized(&German).to_string());
+    /// ```
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<T, E> FmtLocalized for RetryEntry<T, E>
+where
+    T: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        let failed = strings.failed_after(self.attempts.len());
+        write!(f, "{}: {}", self.item, failed)?;
+        for (i, e) in self.attempts.iter().enumerate() {
+            write!(f, "\n  {}: {}", strings.attempt(i + 1), e)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, E> std::error::Error for RetryEntry<T, E>
+where
+    T: fmt::Display + fmt::Debug,
+    E: fmt::Display + fmt::Debug,
+{
+}
+
+impl<T, E> ReportEntry for RetryEntry<T, E>
+where
+    T: fmt::Display,
+    E: fmt::Display,
+{
+    fn key(&self) -> Option<String> {
+        Some(self.item.to_string())
+    }
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        vec![("attempts".to_string(), self.attempts.len().to_string())]
+    }
+}
+
+/// The error of one numbered attempt, produced by [ErrorVec::retry].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt<E> {
+    /// The 1-based attempt number.
+    pub number: usize,
+    /// The error of this attempt.
+    pub error: E,
+}
+
+impl<E> ErrorVec<Attempt<E>> {
+    /// Call `f` with each 1-based attempt number until it succeeds or `max_attempts` have failed,
+    /// returning the first `Ok` value or every attempt's error:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let r = ErrorVec::retry(3, |n| if n < 3 { Err("timeout") } else { Ok(n) });
+    /// assert_eq!(3, r.unwrap());
+    ///
+    /// let ev = ErrorVec::retry(2, |_| Err::<(), _>("connection refused")).unwrap_err();
+    /// let expected_display = r#"
+    /// [error 1 of 2] attempt 1: connection refused
+    ///
+    /// [error 2 of 2] attempt 2: connection refused
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, &ev.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_attempts` is 0.
+    pub fn retry<T, F>(max_attempts: usize, f: F) -> Result<T, Self>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        Self::retry_with_backoff(max_attempts, Duration::ZERO, f)
+    }
+
+    /// As [ErrorVec::retry], sleeping between attempts for `initial_delay`, doubling after each
+    /// failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_attempts` is 0.
+    pub fn retry_with_backoff<T, F>(
+        max_attempts: usize,
+        initial_delay: Duration,
+        mut f: F,
+    ) -> Result<T, Self>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        assert!(max_attempts > 0, "max_attempts must be non-zero");
+        let mut attempts = ErrorVec::default();
+        let mut delay = initial_delay;
+        for number in 1..=max_attempts {
+            if number > 1 && !delay.is_zero() {
+                std::thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+            }
+            match f(number) {
+                Ok(x) => return Ok(x),
+                Err(error) => attempts.push(Attempt { number, error }),
+            }
+        }
+        Err(attempts)
+    }
+}
+
+impl<E> fmt::Display for Attempt<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<E> Attempt<E>
+where
+    E: fmt::Display,
+{
+    /// Display the attempt labeled by [ReportStrings::attempt] from `strings`.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<E> FmtLocalized for Attempt<E>
+where
+    E: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        write!(f, "{}: {}", strings.attempt(self.number), self.error)
+    }
+}
+
+impl<E> std::error::Error for Attempt<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Attempt<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, span, severity, children, code);
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = vec![("attempt".to_string(), self.number.to_string())];
+        metadata.extend(self.error.metadata());
+        metadata
+    }
+}
@@ -0,0 +1,105 @@
+use crate::ErrorVec;
+use std::fmt;
+use std::io;
+use std::ops::Deref;
+
+/// An [ErrorVec] for long-running processes which periodically hand off the errors gathered so
+/// far, while keeping a running total across hand-offs:
+///
+/// ```
+/// use errorvec::RunningErrorVec;
+///
+/// let mut errs = RunningErrorVec::default();
+/// errs.push("disk latency high");
+/// errs.push("upstream 503");
+///
+/// let batch = errs.drain_report();
+/// assert_eq!(2, batch.len());
+/// assert!(errs.is_empty());
+///
+/// errs.push("upstream 503");
+/// let mut log = vec![];
+/// errs.flush_to(&mut log).unwrap();
+///
+/// assert_eq!("[error 1 of 1] upstream 503\n", String::from_utf8(log).unwrap());
+/// assert_eq!(3, errs.total());
+/// ```
+///
+/// Only shared access to the inner [ErrorVec] is provided, so that errors leave only by draining
+/// and the total never decreases.
+#[derive(Debug)]
+pub struct RunningErrorVec<E> {
+    ev: ErrorVec<E>,
+    drained: usize,
+}
+
+impl<E> Default for RunningErrorVec<E> {
+    fn default() -> Self {
+        RunningErrorVec {
+            ev: ErrorVec::default(),
+            drained: 0,
+        }
+    }
+}
+
+impl<E> RunningErrorVec<E> {
+    /// Record `e`.
+    pub fn push(&mut self, e: E) {
+        self.ev.push(e);
+    }
+
+    /// As [ErrorVec::take_error].
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> Option<T> {
+        self.ev.take_error(r)
+    }
+
+    /// Take the errors gathered since the last drain, leaving the accumulator empty.
+    pub fn drain_report(&mut self) -> ErrorVec<E> {
+        let ev = std::mem::take(&mut self.ev);
+        self.drained += ev.len();
+        ev
+    }
+
+    /// The number of errors gathered since creation, including those already drained.
+    pub fn total(&self) -> usize {
+        self.drained + self.ev.len()
+    }
+}
+
+impl<E> RunningErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Write the report of the errors gathered since the last drain to `w` and flush it, then
+    /// drain them, returning how many were written. Nothing is written if there are none.
+    ///
+    /// If writing or flushing fails, the errors are kept for a later attempt.
+    pub fn flush_to<W>(&mut self, mut w: W) -> io::Result<usize>
+    where
+        W: io::Write,
+    {
+        if self.ev.is_empty() {
+            return Ok(0);
+        }
+        self.ev.write_report(&mut w)?;
+        w.flush()?;
+        Ok(self.drain_report().len())
+    }
+}
+
+impl<E> Extend<E> for RunningErrorVec<E> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.ev.extend(iter);
+    }
+}
+
+impl<E> Deref for RunningErrorVec<E> {
+    type Target = ErrorVec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ev
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{ErrorVec, ReportEntry, Severity};
+use serde_json::{json, Value};
+use std::io;
+
+/// The SARIF format version produced by [ErrorVec::to_sarif].
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// The JSON schema URI of the SARIF format produced by [ErrorVec::to_sarif].
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Build a minimal SARIF 2.1 log with a single run from the tool `tool_name`, containing one
+    /// result per entry with a `level` given by [ReportEntry::severity] and a message given by
+    /// [ReportEntry::message]. Entries with a [ReportEntry::code] get a `ruleId`, and entries with
+    /// a [ReportEntry::span] get a physical location:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["unused variable `x`"].into_iter().collect();
+    /// let log = ev.to_sarif("mylint");
+    ///
+    /// assert_eq!(log["version"], "2.1.0");
+    /// assert_eq!(log["runs"][0]["tool"]["driver"]["name"], "mylint");
+    /// assert_eq!(
+    ///     log["runs"][0]["results"][0],
+    ///     serde_json::json!({
+    ///         "level": "error",
+    ///         "message": { "text": "unused variable `x`" },
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// Span columns count characters, so the run declares a `columnKind` of `unicodeCodePoints`
+    /// rather than SARIF's default of UTF-16 code units:
+    ///
+    /// ```
+    /// use errorvec::{Coded, ErrorVec, Span, Spanned};
+    ///
+    /// let text = "let 🦀 = foo;";
+    /// let ev: ErrorVec<_> = [Coded::new(
+    ///     "E0425",
+    ///     Spanned::new(Span::from_bytes("main.rs", text, 11..14), "unknown identifier `foo`"),
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    /// let run = &ev.to_sarif("mylint")["runs"][0];
+    ///
+    /// assert_eq!(run["columnKind"], "unicodeCodePoints");
+    /// assert_eq!(run["results"][0]["ruleId"], "E0425");
+    /// assert_eq!(run["results"][0]["message"]["text"], "unknown identifier `foo`");
+    /// let region = &run["results"][0]["locations"][0]["physicalLocation"]["region"];
+    /// assert_eq!(region["startColumn"], 9);
+    /// assert_eq!(region["endColumn"], 12);
+    /// ```
+    pub fn to_sarif(&self, tool_name: &str) -> Value {
+        let results: Vec<Value> = self.iter().map(sarif_result).collect();
+
+        json!({
+            "version": SARIF_VERSION,
+            "$schema": SARIF_SCHEMA,
+            "runs": [
+                {
+                    "tool": { "driver": { "name": tool_name } },
+                    "columnKind": "unicodeCodePoints",
+                    "results": results,
+                }
+            ],
+        })
+    }
+
+    /// Write the [ErrorVec::to_sarif] log to `w`.
+    pub fn write_sarif<W>(&self, w: W, tool_name: &str) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        serde_json::to_writer_pretty(w, &self.to_sarif(tool_name))?;
+        Ok(())
+    }
+}
+
+fn sarif_result<E>(e: &E) -> Value
+where
+    E: ReportEntry,
+{
+    let level = match e.severity() {
+        Severity::Hint | Severity::Note => "note",
+        Severity::Warning => "warning",
+        Severity::Error | Severity::Fatal => "error",
+    };
+    let mut result = json!({
+        "level": level,
+        "message": { "text": e.message().trim_end() },
+    });
+    if let Some(code) = e.code() {
+        result["ruleId"] = json!(code);
+    }
+    if let Some(span) = e.span() {
+        result["locations"] = json!([
+            {
+                "physicalLocation": {
+                    "artifactLocation": { "uri": span.source },
+                    "region": {
+                        "startLine": span.start.line,
+                        "startColumn": span.start.column,
+                        "endLine": span.end.line,
+                        "endColumn": span.end.column,
+                    },
+                },
+            }
+        ]);
+    }
+    result
+}
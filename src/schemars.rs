@@ -0,0 +1,12 @@
+//! With this feature, the serializable report types, such as [JsonReport](crate::JsonReport) and
+//! [ProblemDetails](crate::ProblemDetails), derive [schemars::JsonSchema]:
+//!
+//! ```
+//! use errorvec::JsonReport;
+//!
+//! let schema = schemars::schema_for!(JsonReport);
+//! assert_eq!(
+//!     Some(&serde_json::json!(["count", "errors"])),
+//!     schema.get("required"),
+//! );
+//! ```
@@ -0,0 +1,113 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+
+/// An error labeled with the scope, such as a processing phase, in which it was recorded.
+///
+/// `Display` prefixes the error with its scope label. See [ErrorVec::in_scope].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Scoped<E> {
+    /// The scope label.
+    pub scope: String,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> Scoped<E> {
+    /// Label `error` with `scope`.
+    pub fn new<S>(scope: S, error: E) -> Self
+    where
+        S: Into<String>,
+    {
+        Scoped {
+            scope: scope.into(),
+            error,
+        }
+    }
+
+    /// Discard the scope label.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Scoped<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.scope, self.error)
+    }
+}
+
+impl<E> std::error::Error for Scoped<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Scoped<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, span, severity, children, code);
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        let mut md = vec![("scope".to_string(), self.scope.clone())];
+        md.extend(self.error.metadata());
+        md
+    }
+
+    fn group(&self) -> Option<(String, &dyn ReportEntry)> {
+        Some((self.scope.clone(), &self.error))
+    }
+}
+
+impl<E> ErrorVec<Scoped<E>> {
+    /// Run `f` with a fresh accumulator, then record every error it gathered labeled with
+    /// `scope`, returning `f`'s result:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// let ast = ev.in_scope("parsing", |ev| {
+    ///     ev.push("unexpected token `}`");
+    ///     "partial ast"
+    /// });
+    /// ev.in_scope("type-checking", |ev| {
+    ///     ev.push("mismatched types");
+    ///     ev.push("unknown identifier `x`");
+    /// });
+    ///
+    /// assert_eq!("partial ast", ast);
+    ///
+    /// let expected_display = r#"
+    /// [error 1 of 3] parsing: unexpected token `}`
+    ///
+    /// [error 2 of 3] type-checking: mismatched types
+    ///
+    /// [error 3 of 3] type-checking: unknown identifier `x`
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, &ev.to_string());
+    /// ```
+    pub fn in_scope<S, F, T>(&mut self, scope: S, f: F) -> T
+    where
+        S: Into<String>,
+        F: FnOnce(&mut ErrorVec<E>) -> T,
+    {
+        let scope = scope.into();
+        let mut inner = ErrorVec::default();
+        let value = f(&mut inner);
+        self.extend(
+            inner
+                .into_iter()
+                .map(|error| Scoped::new(scope.clone(), error)),
+        );
+        value
+    }
+}
@@ -0,0 +1,94 @@
+use crate::errorvec::count_errors;
+use crate::{ErrorVec, ReportEntry, Severity};
+use sentry_core::protocol::{Attachment, Event, Level, Uuid};
+use sentry_core::Scope;
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Report each entry to Sentry as its own event, returning the event ids in order.
+    ///
+    /// Each event is captured within a scope prepared by `scope_config`. Its message is the entry
+    /// text and its level follows [ReportEntry::severity]. It is tagged with `index` and `total`,
+    /// the entry's `key`, `code`, and `location` when present, and each [ReportEntry::metadata]
+    /// pair, such as the tags of [Tagged](crate::Tagged):
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> = ["payment declined", "address invalid"].into_iter().collect();
+    ///
+    /// // Without a bound Sentry client, capturing does nothing.
+    /// let ids = ev.capture_all(|scope| scope.set_tag("job", "checkout"));
+    /// assert_eq!(2, ids.len());
+    /// ```
+    pub fn capture_all<F>(&self, scope_config: F) -> Vec<Uuid>
+    where
+        F: Fn(&mut Scope),
+    {
+        let total = self.len();
+        self.iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let mut event = Event {
+                    message: Some(e.to_string().trim_end().to_string()),
+                    level: sentry_level(e.severity()),
+                    ..Event::default()
+                };
+                event.tags.insert("index".to_string(), (i + 1).to_string());
+                event.tags.insert("total".to_string(), total.to_string());
+                if let Some(key) = e.key() {
+                    event.tags.insert("key".to_string(), key);
+                }
+                if let Some(code) = e.code() {
+                    event.tags.insert("code".to_string(), code);
+                }
+                if let Some(span) = e.span() {
+                    event.tags.insert("location".to_string(), span.to_string());
+                }
+                event.tags.extend(e.metadata());
+                sentry_core::with_scope(&scope_config, || sentry_core::capture_event(event))
+            })
+            .collect()
+    }
+
+    /// Report every entry to Sentry as a single event, for aggregates too large to send one event
+    /// per entry, returning the event id.
+    ///
+    /// The event's message counts the errors, its level is the highest [ReportEntry::severity],
+    /// and the full report is attached as `errors.txt`.
+    pub fn capture_grouped<F>(&self, scope_config: F) -> Uuid
+    where
+        F: Fn(&mut Scope),
+    {
+        let event = Event {
+            message: Some(count_errors(self.len())),
+            level: sentry_level(self.severity()),
+            ..Event::default()
+        };
+        let report = self.to_string();
+        sentry_core::with_scope(
+            |scope| {
+                scope_config(scope);
+                scope.add_attachment(Attachment {
+                    buffer: report.into_bytes(),
+                    filename: "errors.txt".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    ty: None,
+                });
+            },
+            || sentry_core::capture_event(event),
+        )
+    }
+}
+
+fn sentry_level(severity: Severity) -> Level {
+    match severity {
+        Severity::Hint => Level::Debug,
+        Severity::Note => Level::Info,
+        Severity::Warning => Level::Warning,
+        Severity::Error => Level::Error,
+        Severity::Fatal => Level::Fatal,
+    }
+}
@@ -0,0 +1,171 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::cmp::Reverse;
+use std::fmt;
+
+/// How serious an entry is, ordered from least to most severe.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// A suggestion for the user.
+    Hint,
+    /// Informational context.
+    Note,
+    /// A problem which does not prevent progress.
+    Warning,
+    /// A problem which prevents success.
+    #[default]
+    Error,
+    /// A problem which makes continuing pointless.
+    Fatal,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Severity::*;
+
+        f.write_str(match self {
+            Hint => "hint",
+            Note => "note",
+            Warning => "warning",
+            Error => "error",
+            Fatal => "fatal",
+        })
+    }
+}
+
+/// An error paired with its [Severity].
+///
+/// `Display` prefixes the error with its severity:
+///
+/// ```
+/// use errorvec::{Leveled, Severity};
+///
+/// let e = Leveled::new(Severity::Warning, "unused variable `x`");
+/// assert_eq!("warning: unused variable `x`", e.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Leveled<E> {
+    /// How serious the error is.
+    pub severity: Severity,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> Leveled<E> {
+    /// Pair `error` with `severity`.
+    pub fn new(severity: Severity, error: E) -> Self {
+        Leveled { severity, error }
+    }
+
+    /// Discard the severity.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Leveled<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
+}
+
+impl<E> std::error::Error for Leveled<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Leveled<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, metadata, span, children, code);
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+impl<E> ErrorVec<E> {
+    /// The first error with the greatest rank according to `rank`, or `None` if empty.
+    pub fn max_by_rank<K, F>(&self, rank: F) -> Option<&E>
+    where
+        K: Ord,
+        F: Fn(&E) -> K,
+    {
+        let mut best: Option<(K, &E)> = None;
+        for e in self.iter() {
+            let k = rank(e);
+            if best.as_ref().map(|(bk, _)| k > *bk).unwrap_or(true) {
+                best = Some((k, e));
+            }
+        }
+        best.map(|(_, e)| e)
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// The first error with the highest [ReportEntry::severity], or `None` if empty:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Leveled, Severity};
+    ///
+    /// let ev: ErrorVec<_> = [
+    ///     Leveled::new(Severity::Warning, "deprecated option"),
+    ///     Leveled::new(Severity::Fatal, "disk full"),
+    ///     Leveled::new(Severity::Error, "bad record"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!("fatal: disk full", ev.most_severe().unwrap().to_string());
+    /// ```
+    pub fn most_severe(&self) -> Option<&E> {
+        self.max_by_rank(ReportEntry::severity)
+    }
+
+    /// Stably sort the errors from most to least severe:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Leveled, Severity};
+    ///
+    /// let mut ev: ErrorVec<_> = [
+    ///     Leveled::new(Severity::Warning, "deprecated option"),
+    ///     Leveled::new(Severity::Error, "bad record 1"),
+    ///     Leveled::new(Severity::Fatal, "disk full"),
+    ///     Leveled::new(Severity::Error, "bad record 2"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// ev.sort_by_severity();
+    ///
+    /// let expected_display = r#"
+    /// [error 1 of 4] fatal: disk full
+    ///
+    /// [error 2 of 4] error: bad record 1
+    ///
+    /// [error 3 of 4] error: bad record 2
+    ///
+    /// [error 4 of 4] warning: deprecated option
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, &ev.to_string());
+    /// ```
+    pub fn sort_by_severity(&mut self) {
+        self.sort_by_key(|e| Reverse(e.severity()));
+    }
+
+    /// Display the report sorted from most to least severe, leaving `self` unchanged.
+    pub fn display_by_severity(&self) -> impl fmt::Display + '_ {
+        self.display_sorted_by_key(|e| Reverse(e.severity()))
+    }
+}
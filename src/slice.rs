@@ -0,0 +1,40 @@
+use crate::errorvec::report_chunks;
+use crate::ErrorVec;
+use std::fmt;
+
+/// Displays a borrowed slice of errors as the numbered [ErrorVec] report, produced by
+/// [ErrorVec::display_slice].
+#[derive(Copy, Clone, Debug)]
+pub struct ErrorSliceDisplay<'a, E>(pub &'a [E]);
+
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Display errors owned elsewhere as the usual report, without copying them into an
+    /// [ErrorVec]:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let errors = vec!["whoops", "ouch!"];
+    /// let ev: ErrorVec<&str> = errors.clone().into();
+    ///
+    /// assert_eq!(ev.to_string(), ErrorVec::display_slice(&errors).to_string());
+    /// ```
+    pub fn display_slice(errors: &[E]) -> ErrorSliceDisplay<'_, E> {
+        ErrorSliceDisplay(errors)
+    }
+}
+
+impl<E> fmt::Display for ErrorSliceDisplay<'_, E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in report_chunks(self.0) {
+            f.write_str(&chunk)?;
+        }
+        Ok(())
+    }
+}
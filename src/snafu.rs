@@ -0,0 +1,96 @@
+use crate::ErrorVec;
+use snafu::{ErrorCompat, FromString, IntoError, NoneError};
+use std::error::Error;
+
+impl<E> ErrorVec<E>
+where
+    E: Error + ErrorCompat,
+{
+    /// As [ErrorVec::take_error], first applying the snafu `context` selector to any error, as
+    /// [snafu::ResultExt::context] does:
+    ///
+    /// ```
+    /// use snafu::prelude::*;
+    /// use errorvec::ErrorVec;
+    ///
+    /// #[derive(Debug, Snafu)]
+    /// enum ConfigError {
+    ///     #[snafu(display("invalid port {value:?}"))]
+    ///     Port { value: String, source: std::num::ParseIntError },
+    ///     #[snafu(display("no hosts configured"))]
+    ///     NoHosts,
+    /// }
+    ///
+    /// #[derive(Debug, Snafu)]
+    /// enum AppError {
+    ///     #[snafu(display("bad configuration"))]
+    ///     Config { source: ErrorVec<ConfigError> },
+    /// }
+    ///
+    /// fn load(port: &str, hosts: &[&str]) -> Result<u16, AppError> {
+    ///     let mut errs = ErrorVec::default();
+    ///     let port = errs.take_context(port.parse(), PortSnafu { value: port });
+    ///     errs.ensure(!hosts.is_empty(), NoHostsSnafu);
+    ///     errs.into_result_with(port.unwrap_or_default()).context(ConfigSnafu)
+    /// }
+    ///
+    /// let err = load("http", &[]).unwrap_err();
+    /// let AppError::Config { source } = err;
+    /// assert_eq!(2, source.len());
+    /// ```
+    pub fn take_context<T, S, C>(&mut self, r: Result<T, S>, context: C) -> Option<T>
+    where
+        C: IntoError<E, Source = S>,
+    {
+        self.take_error(r.map_err(|e| context.into_error(e)))
+    }
+
+    /// Like [snafu::ensure!], but record the error built from `context` rather than returning it,
+    /// and return `predicate`.
+    pub fn ensure<C>(&mut self, predicate: bool, context: C) -> bool
+    where
+        C: IntoError<E, Source = NoneError>,
+    {
+        if !predicate {
+            self.push(context.into_error(NoneError));
+        }
+        predicate
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: FromString,
+{
+    /// Like [snafu::whatever!], but record the error rather than returning it:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let mut errs = ErrorVec::<snafu::Whatever>::default();
+    /// errs.push_whatever(format!("unknown mode {:?}", "turbo"));
+    /// let n = errs.take_whatever("x".parse::<u32>(), "could not parse count");
+    ///
+    /// assert_eq!(None, n);
+    /// assert_eq!(2, errs.len());
+    /// ```
+    pub fn push_whatever<M>(&mut self, message: M)
+    where
+        M: Into<String>,
+    {
+        self.push(E::without_source(message.into()));
+    }
+
+    /// As [ErrorVec::take_error], recording any error as the source of a new error with
+    /// `message`, as [snafu::ResultExt::whatever_context] does.
+    pub fn take_whatever<T, S, M>(&mut self, r: Result<T, S>, message: M) -> Option<T>
+    where
+        S: Into<E::Source>,
+        M: Into<String>,
+    {
+        self.take_error(r.map_err(|e| E::with_source(e.into(), message.into())))
+    }
+}
+
+/// Allows an [ErrorVec] to be the error type built by snafu context selectors.
+impl<E> ErrorCompat for ErrorVec<E> {}
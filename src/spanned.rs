@@ -0,0 +1,203 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::fmt;
+use std::ops::Range;
+
+/// A 1-based line and column position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column number, counted in `char`s.
+    pub column: usize,
+}
+
+impl LineCol {
+    /// Construct a [LineCol] from 1-based `line` and `column`.
+    pub fn new(line: usize, column: usize) -> Self {
+        LineCol { line, column }
+    }
+
+    /// Compute the [LineCol] of byte `offset` within `text`.
+    ///
+    /// Offsets past the end of `text` are clamped to the end.
+    pub fn of_offset(text: &str, offset: usize) -> Self {
+        let mut offset = offset.min(text.len());
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        LineCol {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// A source location: a source identifier (typically a file path) and a region within it.
+///
+/// Every [Span] has a line/column range; spans created with [Span::from_bytes] also retain the
+/// byte range they were computed from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The source identifier, such as a file path.
+    pub source: String,
+    /// The start of the region.
+    pub start: LineCol,
+    /// The end of the region.
+    pub end: LineCol,
+    /// The byte range of the region, when known.
+    pub bytes: Option<Range<usize>>,
+}
+
+impl Span {
+    /// A [Span] covering `start` to `end` within `source`.
+    pub fn new<S>(source: S, start: LineCol, end: LineCol) -> Self
+    where
+        S: Into<String>,
+    {
+        Span {
+            source: source.into(),
+            start,
+            end,
+            bytes: None,
+        }
+    }
+
+    /// A [Span] for the single position `line`:`column` within `source`.
+    pub fn at<S>(source: S, line: usize, column: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let pos = LineCol::new(line, column);
+        Span::new(source, pos, pos)
+    }
+
+    /// A [Span] for the byte range `bytes` of `text`, which is the content of `source`:
+    ///
+    /// ```
+    /// use errorvec::{LineCol, Span};
+    ///
+    /// let text = "fn main() {\n    let x = ;\n}\n";
+    /// let span = Span::from_bytes("main.rs", text, 24..25);
+    /// assert_eq!(LineCol::new(2, 13), span.start);
+    /// assert_eq!("main.rs:2:13", span.to_string());
+    /// ```
+    pub fn from_bytes<S>(source: S, text: &str, bytes: Range<usize>) -> Self
+    where
+        S: Into<String>,
+    {
+        Span {
+            source: source.into(),
+            start: LineCol::of_offset(text, bytes.start),
+            end: LineCol::of_offset(text, bytes.end),
+            bytes: Some(bytes),
+        }
+    }
+
+    fn sort_key(&self) -> (&str, LineCol, LineCol) {
+        (&self.source, self.start, self.end)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.source, self.start.line, self.start.column
+        )
+    }
+}
+
+/// An error paired with the [Span] where it occurred.
+///
+/// `Display` prefixes the error with its location:
+///
+/// ```
+/// use errorvec::{Span, Spanned};
+///
+/// let e = Spanned::new(Span::at("lib.rs", 3, 7), "unknown identifier `foo`");
+/// assert_eq!("lib.rs:3:7: unknown identifier `foo`", e.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Spanned<E> {
+    /// Where the error occurred.
+    pub span: Span,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> Spanned<E> {
+    /// Pair `error` with `span`.
+    pub fn new(span: Span, error: E) -> Self {
+        Spanned { span, error }
+    }
+
+    /// Discard the span.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Spanned<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.span, self.error)
+    }
+}
+
+impl<E> std::error::Error for Spanned<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Spanned<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, metadata, severity, children, code);
+
+    fn span(&self) -> Option<&Span> {
+        Some(&self.span)
+    }
+}
+
+impl<E> ErrorVec<Spanned<E>> {
+    /// Like [ErrorVec::take_error], attaching `span` to the error if present.
+    pub fn take_error_spanned<T>(&mut self, r: Result<T, E>, span: Span) -> Option<T> {
+        self.take_error(r.map_err(|error| Spanned::new(span, error)))
+    }
+
+    /// Stably sort the errors by source, then by position:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, Span};
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// ev.take_error_spanned(Err::<(), _>("second"), Span::at("a.rs", 9, 1));
+    /// ev.take_error_spanned(Err::<(), _>("third"), Span::at("b.rs", 1, 1));
+    /// ev.take_error_spanned(Err::<(), _>("first"), Span::at("a.rs", 2, 5));
+    /// ev.sort_by_span();
+    ///
+    /// let expected_display = r#"
+    /// [error 1 of 3] a.rs:2:5: first
+    ///
+    /// [error 2 of 3] a.rs:9:1: second
+    ///
+    /// [error 3 of 3] b.rs:1:1: third
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, &ev.to_string());
+    /// ```
+    pub fn sort_by_span(&mut self) {
+        self.sort_by(|a, b| a.span.sort_key().cmp(&b.span.sort_key()));
+    }
+}
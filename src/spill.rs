@@ -0,0 +1,327 @@
+use crate::{ClassSummary, English, ErrorVec, ReportStrings, Summary};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom, Write};
+
+/// An error accumulator for batch jobs producing more errors than fit in memory.
+///
+/// The first `threshold` errors are kept in memory. Beyond that, each error's rendered text is
+/// appended to an anonymous temporary file, which is removed when the accumulator is dropped,
+/// or to the file given to [SpillingErrorVec::spill_to].
+/// The total count and a [Summary] by classification stay in memory, and the full report is
+/// streamed from disk by [SpillingErrorVec::write_report]:
+///
+/// ```
+/// use errorvec::SpillingErrorVec;
+///
+/// let mut errs = SpillingErrorVec::with_classifier(2, |e: &String| e.starts_with("row").then_some("row"));
+/// for i in 1..=3 {
+///     errs.push(format!("row {i}: bad date")).unwrap();
+/// }
+/// errs.push("footer missing".to_string()).unwrap();
+///
+/// assert_eq!(4, errs.len());
+/// assert_eq!(2, errs.spilled());
+/// assert_eq!(3, errs.summary().classes[&Some("row")].count);
+///
+/// let mut out = vec![];
+/// errs.write_report(&mut out).unwrap();
+///
+/// let expected_report = r#"
+/// [error 1 of 4] row 1: bad date
+///
+/// [error 2 of 4] row 2: bad date
+///
+/// [error 3 of 4] row 3: bad date
+///
+/// [error 4 of 4] footer missing
+/// "#.trim_start();
+///
+/// assert_eq!(expected_report, String::from_utf8(out).unwrap());
+/// ```
+pub struct SpillingErrorVec<E, K = ()> {
+    memory: ErrorVec<E>,
+    threshold: usize,
+    file: Option<File>,
+    committed: u64,
+    poisoned: bool,
+    spilled: usize,
+    classify: fn(&E) -> K,
+    summary: Summary<K>,
+}
+
+impl<E> SpillingErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Keep up to `threshold` errors in memory, spilling the rest to disk.
+    pub fn new(threshold: usize) -> Self {
+        SpillingErrorVec::with_classifier(threshold, |_| ())
+    }
+}
+
+impl<E, K> SpillingErrorVec<E, K>
+where
+    E: fmt::Display,
+    K: Ord,
+{
+    /// As [SpillingErrorVec::new], also maintaining a [Summary] by `classify`, as
+    /// [ErrorVec::summarize] does.
+    pub fn with_classifier(threshold: usize, classify: fn(&E) -> K) -> Self {
+        SpillingErrorVec {
+            memory: ErrorVec::default(),
+            threshold,
+            file: None,
+            committed: 0,
+            poisoned: false,
+            spilled: 0,
+            classify,
+            summary: Summary {
+                total: 0,
+                classes: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Spill into `file` rather than an anonymous temporary file. It must be empty and open for
+    /// reading and writing.
+    pub fn spill_to(mut self, file: File) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Record `e`, writing it to disk if the in-memory threshold is reached.
+    ///
+    /// On an I/O error, `e` is not recorded, and any partly written record is removed. If that
+    /// removal also fails, the spill file is in an unknown state, and every later call to
+    /// [SpillingErrorVec::push] or [SpillingErrorVec::write_report] fails:
+    ///
+    /// ```
+    /// use errorvec::SpillingErrorVec;
+    ///
+    /// let path = std::env::temp_dir().join("errorvec-spill-readonly");
+    /// std::fs::write(&path, "").unwrap();
+    /// let read_only = std::fs::File::open(&path).unwrap();
+    ///
+    /// let mut errs = SpillingErrorVec::new(1).spill_to(read_only);
+    /// errs.push("kept in memory").unwrap();
+    /// assert!(errs.push("spilled").is_err());
+    /// assert_eq!(1, errs.len());
+    ///
+    /// assert!(errs.push("spilled").is_err());
+    /// assert!(errs.write_report(vec![]).is_err());
+    /// ```
+    pub fn push(&mut self, e: E) -> io::Result<()> {
+        let class = (self.classify)(&e);
+        if self.memory.len() < self.threshold {
+            self.memory.push(e);
+        } else {
+            self.check_poisoned()?;
+            let text = e.to_string();
+            let text = text.trim_end();
+            let mut record = Vec::with_capacity(8 + text.len());
+            record.extend_from_slice(&(text.len() as u64).to_le_bytes());
+            record.extend_from_slice(text.as_bytes());
+            self.append(&record)?;
+            self.spilled += 1;
+        }
+
+        let index = self.summary.total;
+        self.summary
+            .classes
+            .entry(class)
+            .and_modify(|c| {
+                c.count += 1;
+                c.last = index;
+            })
+            .or_insert(ClassSummary {
+                count: 1,
+                first: index,
+                last: index,
+            });
+        self.summary.total += 1;
+        Ok(())
+    }
+
+    /// Write `record` at the end of the committed records, removing any part written on failure.
+    fn append(&mut self, record: &[u8]) -> io::Result<()> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(tempfile::tempfile()?),
+        };
+        let result = file
+            .seek(SeekFrom::Start(self.committed))
+            .and_then(|_| file.write_all(record));
+        match result {
+            Ok(()) => {
+                self.committed += record.len() as u64;
+                Ok(())
+            }
+            Err(e) => {
+                if file.set_len(self.committed).is_err() {
+                    self.poisoned = true;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// As [ErrorVec::take_error], failing only if recording the error fails.
+    pub fn take_error<T>(&mut self, r: Result<T, E>) -> io::Result<Option<T>> {
+        match r {
+            Ok(v) => Ok(Some(v)),
+            Err(e) => self.push(e).map(|()| None),
+        }
+    }
+}
+
+impl<E, K> SpillingErrorVec<E, K> {
+    /// Fail if an earlier I/O error left the spill file in an unknown state.
+    fn check_poisoned(&self) -> io::Result<()> {
+        if self.poisoned {
+            Err(io::Error::other(
+                "spill file is in an unknown state after an earlier I/O error",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The total number of errors, in memory and on disk.
+    pub fn len(&self) -> usize {
+        self.summary.total
+    }
+
+    /// True when no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.summary.total == 0
+    }
+
+    /// The number of errors written to disk.
+    pub fn spilled(&self) -> usize {
+        self.spilled
+    }
+
+    /// The errors kept in memory, which precede any spilled to disk.
+    pub fn in_memory(&self) -> &ErrorVec<E> {
+        &self.memory
+    }
+
+    /// The summary of every error recorded.
+    pub fn summary(&self) -> &Summary<K> {
+        &self.summary
+    }
+}
+
+impl<E, K> SpillingErrorVec<E, K>
+where
+    E: fmt::Display,
+{
+    /// Write the full report to `w`, in the format of the [ErrorVec] `Display` report, reading
+    /// spilled errors back from disk one at a time.
+    ///
+    /// Recording may continue after this returns, even if it fails:
+    ///
+    /// ```
+    /// use std::io::{self, Write};
+    /// use errorvec::SpillingErrorVec;
+    ///
+    /// struct BrokenPipe;
+    ///
+    /// impl Write for BrokenPipe {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(io::ErrorKind::BrokenPipe.into())
+    ///     }
+    ///
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut errs = SpillingErrorVec::new(0);
+    /// for i in 0..100 {
+    ///     errs.push(format!("row {i}: {}", "x".repeat(100))).unwrap();
+    /// }
+    /// assert!(errs.write_report(BrokenPipe).is_err());
+    ///
+    /// errs.push("footer missing".to_string()).unwrap();
+    /// let mut out = vec![];
+    /// errs.write_report(&mut out).unwrap();
+    ///
+    /// let report = String::from_utf8(out).unwrap();
+    /// assert!(report.starts_with("[error 1 of 101] row 0: xxx"));
+    /// assert!(report.ends_with("\n\n[error 101 of 101] footer missing\n"));
+    /// ```
+    pub fn write_report<W>(&self, w: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        self.write_report_localized(w, &English)
+    }
+
+    /// As [SpillingErrorVec::write_report], with the framing text from `strings`.
+    pub fn write_report_localized<W, S>(&self, mut w: W, strings: &S) -> io::Result<()>
+    where
+        W: Write,
+        S: ReportStrings + ?Sized,
+    {
+        self.check_poisoned()?;
+        let total = self.len();
+        if total == 0 {
+            return writeln!(w, "{}", strings.no_errors());
+        }
+
+        let mut write_entry = |i: usize, text: &str| {
+            let sep = if i + 1 < total { "\n" } else { "" };
+            write!(w, "{} {}\n{}", strings.entry_header(i, total), text, sep)
+        };
+
+        for (i, e) in self.memory.iter().enumerate() {
+            write_entry(i, e.to_string().trim_end())?;
+        }
+
+        // Reading moves the file cursor, but `push` seeks to the end of the committed records
+        // before each write.
+        if let Some(file) = &self.file {
+            read_spilled(file, self.memory.len()..total, &mut write_entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read the spilled entries at `indices` from the start of `file`, passing each to `write_entry`.
+fn read_spilled<F>(
+    file: &File,
+    indices: std::ops::Range<usize>,
+    mut write_entry: F,
+) -> io::Result<()>
+where
+    F: FnMut(usize, &str) -> io::Result<()>,
+{
+    let mut r = BufReader::new(file);
+    r.seek(SeekFrom::Start(0))?;
+    for i in indices {
+        let mut len = [0; 8];
+        r.read_exact(&mut len)?;
+        let mut text = vec![0; u64::from_le_bytes(len) as usize];
+        r.read_exact(&mut text)?;
+        let text =
+            String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        write_entry(i, &text)?;
+    }
+    Ok(())
+}
+
+impl<E, K> fmt::Debug for SpillingErrorVec<E, K>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpillingErrorVec")
+            .field("memory", &self.memory)
+            .field("threshold", &self.threshold)
+            .field("spilled", &self.spilled)
+            .finish()
+    }
+}
@@ -0,0 +1,182 @@
+use crate::errorvec::{count_errors, count_of, entry_header};
+use crate::{Retention, Severity};
+use std::fmt;
+
+/// The framing text of a report, around the entries themselves, so that reports can be rendered
+/// in the user's language. Entry messages remain the caller's responsibility.
+///
+/// These views take their framing text from a [ReportStrings]:
+///
+/// - `display_localized` on [ErrorVec](crate::ErrorVec), [BoundedErrorVec](crate::BoundedErrorVec),
+///   [CappedErrorVec](crate::CappedErrorVec), [TruncatedErrorVec](crate::TruncatedErrorVec),
+///   [PartialOutcome](crate::PartialOutcome), [GroupedErrorVec](crate::GroupedErrorVec), and
+///   [Summary](crate::Summary)
+/// - [ErrorVec::display_with_summary_localized](crate::ErrorVec::display_with_summary_localized)
+/// - `localized` on `ColoredReport`, [WrappedReport](crate::WrappedReport), and
+///   [TreeReport](crate::TreeReport)
+/// - `ErrorVec::write_html_report_localized` and `SpillingErrorVec::write_report_localized`
+/// - [ErrorVec::display_entries_localized](crate::ErrorVec::display_entries_localized)
+/// - `display_localized` on [InternedErrorVec](crate::InternedErrorVec), and on the entries
+///   which add text of their own: [InternedEntry](crate::InternedEntry),
+///   [RetryEntry](crate::RetryEntry), and [Attempt](crate::Attempt)
+///
+/// Entries are otherwise rendered by their own `Display`, so a report of [Attempt](crate::Attempt)
+/// or [RetryEntry](crate::RetryEntry) entries localizes their text only when each is rendered with
+/// its `display_localized`. The `Debug` output, the Markdown report, and the machine-readable
+/// formats remain English.
+///
+/// Every method defaults to the English text of the `Display` report, so an implementation only
+/// overrides what it translates:
+///
+/// ```
+/// use errorvec::{ErrorVec, ReportStrings};
+///
+/// struct German;
+///
+/// impl ReportStrings for German {
+///     fn error_count(&self, n: usize) -> String {
+///         format!("{} Fehler", n)
+///     }
+///
+///     fn entry_header(&self, index: usize, total: usize) -> String {
+///         format!("[Fehler {} von {}]", index + 1, total)
+///     }
+///
+///     fn summary_header(&self, n: usize, subject: Option<&str>) -> String {
+///         match subject {
+///             Some(s) => format!("Fehler: {} beim {}:", self.error_count(n), s),
+///             None => format!("Fehler: {}:", self.error_count(n)),
+///         }
+///     }
+/// }
+///
+/// let ev: ErrorVec<&str> = ["`name` fehlt", "ungültiger Port"].into_iter().collect();
+///
+/// let expected_display = r#"
+/// Fehler: 2 Fehler beim Laden der Konfiguration:
+/// [Fehler 1 von 2] `name` fehlt
+///
+/// [Fehler 2 von 2] ungültiger Port
+/// "#.trim_start();
+///
+/// assert_eq!(
+///     expected_display,
+///     ev.display_with_summary_localized(Some("Laden der Konfiguration"), &German).to_string(),
+/// );
+/// assert_eq!(
+///     "[no errors]\n",
+///     ErrorVec::<&str>::default().display_localized(&German).to_string(),
+/// );
+/// ```
+pub trait ReportStrings {
+    /// A count of `n` errors, such as `"1 error"` or `"2 errors"`.
+    fn error_count(&self, n: usize) -> String {
+        count_errors(n)
+    }
+
+    /// The header of the entry at 0-based `index` of `total`, such as `"[error 1 of 2]"`.
+    fn entry_header(&self, index: usize, total: usize) -> String {
+        entry_header(index, total)
+    }
+
+    /// The report of no errors, `"[no errors]"`.
+    fn no_errors(&self) -> String {
+        "[no errors]".to_string()
+    }
+
+    /// The overview line above a report of `n` errors, naming the `subject` if any, such as
+    /// `"error: 2 errors occurred while loading configuration:"`.
+    fn summary_header(&self, n: usize, subject: Option<&str>) -> String {
+        match subject {
+            Some(s) => format!("error: {} occurred while {}:", self.error_count(n), s),
+            None => format!("error: {} occurred:", self.error_count(n)),
+        }
+    }
+
+    /// The trailer noting `n` further errors were not recorded, such as
+    /// `"[2 more errors not recorded]"`.
+    fn not_recorded(&self, n: usize) -> String {
+        format!("[{} not recorded]", count_of(n, "more error"))
+    }
+
+    /// The trailer noting `n` errors were dropped to stay within capacity, which are the later
+    /// ones for [Retention::First] and the earlier ones for [Retention::Last], such as
+    /// `"[3 earlier errors dropped]"`.
+    fn dropped(&self, n: usize, retention: Retention) -> String {
+        let noun = match retention {
+            Retention::First => "later error",
+            Retention::Last => "earlier error",
+        };
+        format!("[{} dropped]", count_of(n, noun))
+    }
+
+    /// The trailer noting gathering stopped at a fatal error.
+    fn truncated(&self) -> String {
+        "[stopped at a fatal error; remaining input skipped]".to_string()
+    }
+
+    /// The trailer noting gathering was cancelled.
+    fn cancelled(&self) -> String {
+        "[cancelled; remaining input skipped]".to_string()
+    }
+
+    /// The label of the attempt numbered `number` from 1, such as `"attempt 2"`.
+    fn attempt(&self, number: usize) -> String {
+        format!("attempt {}", number)
+    }
+
+    /// The note that an item failed all `n` of its attempts, such as `"failed after 3 attempts"`.
+    fn failed_after(&self, n: usize) -> String {
+        format!("failed after {}", count_of(n, "attempt"))
+    }
+
+    /// The note that an entry occurred `n` times, such as `"(3 occurrences)"`.
+    fn occurrences(&self, n: usize) -> String {
+        format!("({} occurrences)", n)
+    }
+
+    /// The label introducing a cause in a tree report, `"caused by"`.
+    fn caused_by(&self) -> String {
+        "caused by".to_string()
+    }
+
+    /// The name of a severity level, such as `"warning"`.
+    fn severity(&self, severity: Severity) -> String {
+        severity.to_string()
+    }
+
+    /// The language tag of these strings, for documents which declare one, such as `"en"`.
+    fn language(&self) -> String {
+        "en".to_string()
+    }
+}
+
+/// The English [ReportStrings] used by `Display`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+impl ReportStrings for English {}
+
+/// A report type whose `Display` output can be rendered with any [ReportStrings].
+pub(crate) trait FmtLocalized {
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized;
+}
+
+/// Displays `value` with the framing text from `strings`, as returned by each
+/// `display_localized` method.
+pub(crate) struct Localized<'a, T: ?Sized, S: ?Sized> {
+    pub(crate) value: &'a T,
+    pub(crate) strings: &'a S,
+}
+
+impl<T, S> fmt::Display for Localized<'_, T, S>
+where
+    T: FmtLocalized + ?Sized,
+    S: ReportStrings + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt_localized(f, self.strings)
+    }
+}
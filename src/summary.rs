@@ -0,0 +1,115 @@
+use crate::strings::{FmtLocalized, Localized};
+use crate::{English, ErrorVec, ReportStrings};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Aggregate statistics over an [ErrorVec], produced by [ErrorVec::summarize].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary<K> {
+    /// The total number of errors.
+    pub total: usize,
+    /// The statistics of each classification, ordered by key.
+    pub classes: BTreeMap<K, ClassSummary>,
+}
+
+/// Statistics for one classification within a [Summary].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClassSummary {
+    /// The number of errors in this class.
+    pub count: usize,
+    /// The 0-based index of the first error in this class.
+    pub first: usize,
+    /// The 0-based index of the last error in this class.
+    pub last: usize,
+}
+
+impl<E> ErrorVec<E> {
+    /// Count the errors, in total and per classification key given by `classify`:
+    ///
+    /// ```
+    /// use std::io;
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<io::Error> = [
+    ///     io::ErrorKind::NotFound,
+    ///     io::ErrorKind::PermissionDenied,
+    ///     io::ErrorKind::NotFound,
+    /// ]
+    /// .into_iter()
+    /// .map(io::Error::from)
+    /// .collect();
+    ///
+    /// let summary = ev.summarize(|e| format!("{:?}", e.kind()));
+    /// assert_eq!(3, summary.total);
+    /// assert_eq!(2, summary.classes["NotFound"].count);
+    /// assert_eq!(2, summary.classes["NotFound"].last);
+    /// assert_eq!("3 errors: NotFound (2), PermissionDenied (1)", summary.to_string());
+    /// ```
+    pub fn summarize<K, F>(&self, classify: F) -> Summary<K>
+    where
+        K: Ord,
+        F: Fn(&E) -> K,
+    {
+        let mut classes: BTreeMap<K, ClassSummary> = BTreeMap::new();
+        for (i, e) in self.iter().enumerate() {
+            classes
+                .entry(classify(e))
+                .and_modify(|c| {
+                    c.count += 1;
+                    c.last = i;
+                })
+                .or_insert(ClassSummary {
+                    count: 1,
+                    first: i,
+                    last: i,
+                });
+        }
+
+        Summary {
+            total: self.len(),
+            classes,
+        }
+    }
+}
+
+impl<K> fmt::Display for Summary<K>
+where
+    K: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_localized(f, &English)
+    }
+}
+
+impl<K> Summary<K>
+where
+    K: fmt::Display,
+{
+    /// Display the summary with its [ReportStrings::error_count] from `strings`.
+    pub fn display_localized<'a, S>(&'a self, strings: &'a S) -> impl fmt::Display + 'a
+    where
+        S: ReportStrings + ?Sized,
+    {
+        Localized {
+            value: self,
+            strings,
+        }
+    }
+}
+
+impl<K> FmtLocalized for Summary<K>
+where
+    K: fmt::Display,
+{
+    fn fmt_localized<S>(&self, f: &mut fmt::Formatter, strings: &S) -> fmt::Result
+    where
+        S: ReportStrings + ?Sized,
+    {
+        f.write_str(&strings.error_count(self.total))?;
+        for (i, (k, c)) in self.classes.iter().enumerate() {
+            let sep = if i == 0 { ": " } else { ", " };
+            write!(f, "{}{} ({})", sep, k, c.count)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::entry::forward_report_entry;
+use crate::{ErrorVec, ReportEntry};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// An error with arbitrary key/value tags, such as a record id or tenant.
+///
+/// `Display` appends the tags in key order, and they are included in [ReportEntry::metadata], so
+/// they appear in the metadata of the JSON report:
+///
+/// ```
+/// use errorvec::ErrorVec;
+///
+/// let mut errs = ErrorVec::default();
+/// errs.push_tagged("quota exceeded".to_string(), [("tenant", "acme"), ("shard", "7")]);
+/// errs.take_error_tagged("x".parse::<u32>().map_err(|e| e.to_string()), [("record", "42")]);
+///
+/// let expected_display = r#"
+/// [error 1 of 2] quota exceeded [shard=7, tenant=acme]
+///
+/// [error 2 of 2] invalid digit found in string [record=42]
+/// "#.trim_start();
+///
+/// assert_eq!(expected_display, &errs.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tagged<E> {
+    /// The tags, ordered by key.
+    pub tags: BTreeMap<String, String>,
+    /// The error itself.
+    pub error: E,
+}
+
+impl<E> Tagged<E> {
+    /// Wrap `error` with no tags.
+    pub fn new(error: E) -> Self {
+        Tagged {
+            tags: BTreeMap::new(),
+            error,
+        }
+    }
+
+    /// Add or replace the tag `key`.
+    pub fn with_tag<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: ToString,
+    {
+        self.tags.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Discard the tags.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> ErrorVec<Tagged<E>> {
+    /// Record `e` with `tags`.
+    pub fn push_tagged<I, K, V>(&mut self, e: E, tags: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToString,
+    {
+        let tagged = tags
+            .into_iter()
+            .fold(Tagged::new(e), |t, (k, v)| t.with_tag(k, v));
+        self.push(tagged);
+    }
+
+    /// Like [ErrorVec::take_error], attaching `tags` to the error if present.
+    pub fn take_error_tagged<T, I, K, V>(&mut self, r: Result<T, E>, tags: I) -> Option<T>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToString,
+    {
+        r.map_err(|e| self.push_tagged(e, tags)).ok()
+    }
+}
+
+impl<E> fmt::Display for Tagged<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)?;
+        if !self.tags.is_empty() {
+            f.write_str(" [")?;
+            for (i, (k, v)) in self.tags.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}={}", k, v)?;
+            }
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> std::error::Error for Tagged<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> ReportEntry for Tagged<E>
+where
+    E: ReportEntry,
+{
+    forward_report_entry!(error; message, key, source_chain, span, severity, children, code);
+
+    fn metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = self.error.metadata();
+        metadata.extend(self.tags.iter().map(|(k, v)| (k.clone(), v.clone())));
+        metadata
+    }
+}
@@ -0,0 +1,48 @@
+use crate::errorvec::count_of;
+use crate::{ErrorVec, ReportEntry};
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Build an `INVALID_ARGUMENT` [tonic::Status] whose details carry a `google.rpc.BadRequest`
+    /// with one field violation per entry.
+    ///
+    /// Each violation's `field` is the entry's [ReportEntry::key] (or empty), and its
+    /// `description` is the entry's display text:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    /// use tonic::Code;
+    /// use tonic_types::StatusExt;
+    ///
+    /// let ev: ErrorVec<&str> = ["name is required", "age must be positive"].into_iter().collect();
+    /// let status = ev.to_tonic_status();
+    ///
+    /// assert_eq!(Code::InvalidArgument, status.code());
+    /// let bad_request = status.get_details_bad_request().unwrap();
+    /// assert_eq!(2, bad_request.field_violations.len());
+    /// assert_eq!("age must be positive", bad_request.field_violations[1].description);
+    /// ```
+    pub fn to_tonic_status(&self) -> Status {
+        let mut details = ErrorDetails::new();
+        for e in self.iter() {
+            details
+                .add_bad_request_violation(e.key().unwrap_or_default(), e.to_string().trim_end());
+        }
+
+        let message = count_of(self.len(), "invalid argument");
+        Status::with_error_details(Code::InvalidArgument, message, details)
+    }
+}
+
+impl<E> From<ErrorVec<E>> for Status
+where
+    E: ReportEntry,
+{
+    fn from(ev: ErrorVec<E>) -> Self {
+        ev.to_tonic_status()
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{English, ErrorVec, ReportEntry, ReportStrings};
+use std::fmt;
+
+/// The [ErrorVec] report rendered as a tree, produced by [ErrorVec::display_tree].
+#[derive(Debug)]
+pub struct TreeReport<'a, E, S: ?Sized = English> {
+    ev: &'a ErrorVec<E>,
+    strings: &'a S,
+}
+
+impl<'a, E, S: ?Sized> TreeReport<'a, E, S> {
+    /// Use the framing text from `strings`; see [ReportStrings].
+    pub fn localized<T>(self, strings: &'a T) -> TreeReport<'a, E, T>
+    where
+        T: ReportStrings + ?Sized,
+    {
+        TreeReport {
+            ev: self.ev,
+            strings,
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Display the errors as a tree, with entries that have [ReportEntry::children], such as
+    /// nested [ErrorVec]s, as branches, and each entry's [ReportEntry::source_chain] beneath it:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let stage1: ErrorVec<&str> = ["missing field `id`", "unknown field `nme`"].into_iter().collect();
+    /// let stage2: ErrorVec<&str> = ["dangling reference"].into_iter().collect();
+    /// let ev: ErrorVec<_> = [stage1, stage2].into_iter().collect();
+    ///
+    /// let expected = r#"
+    /// 2 errors
+    /// ├── 2 errors
+    /// │   ├── missing field `id`
+    /// │   └── unknown field `nme`
+    /// └── 1 error
+    ///     └── dangling reference
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected, ev.display_tree().to_string());
+    /// ```
+    ///
+    /// Entries with a [ReportEntry::group], such as [Scoped](crate::Scoped) errors or the
+    /// [Context](crate::Context) of a flattened [GroupedErrorVec](crate::GroupedErrorVec), are
+    /// nested beneath a branch for their label, placed where its first entry appears:
+    ///
+    /// ```
+    /// use errorvec::{ErrorVec, GroupedErrorVec};
+    ///
+    /// let mut ev = ErrorVec::default();
+    /// ev.in_scope("parsing", |ev| ev.push("unexpected token `}`"));
+    /// ev.in_scope("type-checking", |ev| {
+    ///     ev.push("mismatched types");
+    ///     ev.push("unknown identifier `x`");
+    /// });
+    ///
+    /// let expected = r#"
+    /// 3 errors
+    /// ├── parsing
+    /// │   └── unexpected token `}`
+    /// └── type-checking
+    ///     ├── mismatched types
+    ///     └── unknown identifier `x`
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected, ev.display_tree().to_string());
+    ///
+    /// let mut grouped = GroupedErrorVec::default();
+    /// grouped.push("users.csv", "line 3: missing email");
+    /// grouped.push("orders.csv", "line 9: unknown user");
+    /// grouped.push("users.csv", "line 7: duplicate id");
+    ///
+    /// let expected = r#"
+    /// 3 errors
+    /// ├── orders.csv
+    /// │   └── line 9: unknown user
+    /// └── users.csv
+    ///     ├── line 3: missing email
+    ///     └── line 7: duplicate id
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected, grouped.into_errorvec().display_tree().to_string());
+    /// ```
+    pub fn display_tree(&self) -> TreeReport<'_, E> {
+        TreeReport {
+            ev: self,
+            strings: &English,
+        }
+    }
+}
+
+impl<E, S> fmt::Display for TreeReport<'_, E, S>
+where
+    E: ReportEntry,
+    S: ReportStrings + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.strings.error_count(self.ev.len()))?;
+        let nodes = nodes(self.ev.iter().map(|e| e as &dyn ReportEntry));
+        let total = nodes.len();
+        for (i, node) in nodes.iter().enumerate() {
+            write_node(f, self.strings, node, "", i + 1 == total)?;
+        }
+        Ok(())
+    }
+}
+
+/// A branch or leaf of the tree: an entry, or the entries sharing a [ReportEntry::group] label.
+enum Node<'a> {
+    Entry(&'a dyn ReportEntry),
+    Group(String, Vec<&'a dyn ReportEntry>),
+}
+
+/// Gather `entries` into nodes, placing each group where its first entry appears.
+fn nodes<'a, I>(entries: I) -> Vec<Node<'a>>
+where
+    I: IntoIterator<Item = &'a dyn ReportEntry>,
+{
+    let mut nodes = vec![];
+    for e in entries {
+        let Some((label, member)) = e.group() else {
+            nodes.push(Node::Entry(e));
+            continue;
+        };
+        let group = nodes.iter_mut().find_map(|node| match node {
+            Node::Group(l, members) if *l == label => Some(members),
+            _ => None,
+        });
+        match group {
+            Some(members) => members.push(member),
+            None => nodes.push(Node::Group(label, vec![member])),
+        }
+    }
+    nodes
+}
+
+fn write_node<S>(
+    f: &mut fmt::Formatter,
+    strings: &S,
+    node: &Node,
+    prefix: &str,
+    last: bool,
+) -> fmt::Result
+where
+    S: ReportStrings + ?Sized,
+{
+    let (branch, indent) = if last {
+        ("└── ", "    ")
+    } else {
+        ("├── ", "│   ")
+    };
+    let child_prefix = format!("{}{}", prefix, indent);
+
+    let (label, children, causes) = match node {
+        Node::Entry(e) => {
+            let children = e.children();
+            let label = if children.is_empty() {
+                e.to_string().trim_end().to_string()
+            } else {
+                e.key()
+                    .unwrap_or_else(|| strings.error_count(children.len()))
+            };
+            (label, nodes(children), e.source_chain())
+        }
+        Node::Group(label, members) => (label.clone(), nodes(members.iter().copied()), vec![]),
+    };
+    let mut lines = label.lines();
+    writeln!(f, "{}{}{}", prefix, branch, lines.next().unwrap_or(""))?;
+    for line in lines {
+        writeln!(f, "{}{}", child_prefix, line)?;
+    }
+
+    let count = children.len() + causes.len();
+    for (i, child) in children.iter().enumerate() {
+        write_node(f, strings, child, &child_prefix, i + 1 == count)?;
+    }
+    for (i, cause) in causes.iter().enumerate() {
+        let last = i + 1 == causes.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        let mut lines = cause.lines();
+        writeln!(
+            f,
+            "{}{}{}: {}",
+            child_prefix,
+            branch,
+            strings.caused_by(),
+            lines.next().unwrap_or("")
+        )?;
+        for line in lines {
+            writeln!(f, "{}{}{}", child_prefix, indent, line)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::{ErrorVec, ReportEntry};
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::JsValue;
+
+impl<E> ErrorVec<E>
+where
+    E: ReportEntry,
+{
+    /// Convert into a JavaScript `Array` with one object per error, for rendering each error
+    /// individually in a WASM frontend.
+    ///
+    /// Each object has the fields of [JsonEntry](crate::JsonEntry): a 1-based `index`, `key`
+    /// and `code` when present, `message`, a `sources` array, and a `metadata` object of strings.
+    ///
+    /// ```no_run
+    /// use errorvec::ErrorVec;
+    /// use wasm_bindgen::JsValue;
+    ///
+    /// fn validate(input: &str) -> Result<(), JsValue> {
+    ///     let mut errs = ErrorVec::default();
+    ///     if input.is_empty() {
+    ///         errs.push("input is empty");
+    ///     }
+    ///     // Reaches JavaScript as an array of error objects.
+    ///     errs.into_result()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_js_array(&self) -> Array {
+        self.iter()
+            .enumerate()
+            .map(|(i, e)| JsValue::from(js_entry(i + 1, e)))
+            .collect()
+    }
+}
+
+impl<E> From<ErrorVec<E>> for JsValue
+where
+    E: ReportEntry,
+{
+    fn from(ev: ErrorVec<E>) -> Self {
+        ev.to_js_array().into()
+    }
+}
+
+fn js_entry<E>(index: usize, e: &E) -> Object
+where
+    E: ReportEntry,
+{
+    let obj = Object::new();
+    set(&obj, "index", JsValue::from(index as u32));
+    if let Some(key) = e.key() {
+        set(&obj, "key", JsValue::from(key));
+    }
+    if let Some(code) = e.code() {
+        set(&obj, "code", JsValue::from(code));
+    }
+    set(&obj, "message", JsValue::from(e.to_string().trim_end()));
+    let sources: Array = e.source_chain().into_iter().map(JsValue::from).collect();
+    set(&obj, "sources", sources.into());
+    let metadata = Object::new();
+    for (name, value) in e.metadata() {
+        set(&metadata, &name, JsValue::from(value));
+    }
+    set(&obj, "metadata", metadata.into());
+    obj
+}
+
+fn set(obj: &Object, name: &str, value: JsValue) {
+    // Setting a property on a plain object cannot fail.
+    let _ = Reflect::set(obj, &JsValue::from(name), &value);
+}
@@ -0,0 +1,125 @@
+use crate::{English, ErrorVec, ReportStrings};
+use std::fmt;
+
+/// The width used by [ErrorVec::display_wrapped_to_terminal] when `COLUMNS` is unset or invalid.
+pub const DEFAULT_WRAP_WIDTH: usize = 80;
+
+/// The [ErrorVec] report with entry text wrapped to a width, produced by
+/// [ErrorVec::display_wrapped].
+#[derive(Debug)]
+pub struct WrappedReport<'a, E, S: ?Sized = English> {
+    ev: &'a ErrorVec<E>,
+    width: usize,
+    strings: &'a S,
+}
+
+impl<'a, E, S: ?Sized> WrappedReport<'a, E, S> {
+    /// Use the framing text from `strings`; see [ReportStrings].
+    pub fn localized<T>(self, strings: &'a T) -> WrappedReport<'a, E, T>
+    where
+        T: ReportStrings + ?Sized,
+    {
+        WrappedReport {
+            ev: self.ev,
+            width: self.width,
+            strings,
+        }
+    }
+}
+
+impl<E> ErrorVec<E>
+where
+    E: fmt::Display,
+{
+    /// Display the report with each entry's text wrapped at word boundaries to fit `width`
+    /// columns, indenting continuation lines beneath the text following the `[error K of N]`
+    /// header. Words too long to fit are left unbroken:
+    ///
+    /// ```
+    /// use errorvec::ErrorVec;
+    ///
+    /// let ev: ErrorVec<&str> =
+    ///     ["the quick brown fox jumps over the lazy dog"].into_iter().collect();
+    ///
+    /// let expected_display = r#"
+    /// [error 1 of 1] the quick brown
+    ///                fox jumps over
+    ///                the lazy dog
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(expected_display, ev.display_wrapped(30).to_string());
+    /// ```
+    pub fn display_wrapped(&self, width: usize) -> WrappedReport<'_, E> {
+        WrappedReport {
+            ev: self,
+            width,
+            strings: &English,
+        }
+    }
+
+    /// As [ErrorVec::display_wrapped], with the width taken from the `COLUMNS` environment
+    /// variable, or [DEFAULT_WRAP_WIDTH].
+    pub fn display_wrapped_to_terminal(&self) -> WrappedReport<'_, E> {
+        let width = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|c| c.parse().ok())
+            .filter(|&w| w > 0)
+            .unwrap_or(DEFAULT_WRAP_WIDTH);
+        self.display_wrapped(width)
+    }
+}
+
+impl<E, S> fmt::Display for WrappedReport<'_, E, S>
+where
+    E: fmt::Display,
+    S: ReportStrings + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.ev.is_empty() {
+            return self.ev.display_localized(self.strings).fmt(f);
+        }
+
+        let total = self.ev.len();
+        for (i, e) in self.ev.iter().enumerate() {
+            let header = self.strings.entry_header(i, total);
+            let indent = " ".repeat(header.chars().count() + 1);
+            let text_width = self.width.saturating_sub(indent.len()).max(1);
+
+            f.write_str(&header)?;
+            let mut prefix = " ";
+            for line in e.to_string().trim_end().lines() {
+                for wrapped in wrap_line(line, text_width) {
+                    writeln!(f, "{}{}", prefix, wrapped)?;
+                    prefix = &indent;
+                }
+            }
+            if i + 1 < total {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Greedily split `line` at spaces into pieces of at most `width` characters, keeping its leading
+/// whitespace on the first piece.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let body = line.trim_start();
+    let mut pieces = vec![];
+    let mut current = line[..line.len() - body.len()].to_string();
+    let mut has_word = false;
+    for word in body.split(' ').filter(|w| !w.is_empty()) {
+        let needed = current.chars().count() + usize::from(has_word) + word.chars().count();
+        if has_word && needed > width {
+            pieces.push(std::mem::take(&mut current));
+            has_word = false;
+        }
+        if has_word {
+            current.push(' ');
+        }
+        current.push_str(word);
+        has_word = true;
+    }
+    pieces.push(current);
+    pieces
+}